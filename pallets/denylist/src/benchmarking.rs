@@ -0,0 +1,47 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks_instance_pallet! {
+	add_account {
+		let origin = T::ManagerOrigin::try_successful_origin().unwrap();
+		let who: T::AccountId = account("who", 0, 0);
+	}: _<T::RuntimeOrigin>(origin, who.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AccountAdded { who }.into());
+	}
+
+	remove_account {
+		let origin = T::ManagerOrigin::try_successful_origin().unwrap();
+		let who: T::AccountId = account("who", 0, 0);
+		DeniedAccounts::<T, I>::insert(&who, ());
+	}: _<T::RuntimeOrigin>(origin, who.clone())
+	verify {
+		assert_last_event::<T, I>(Event::AccountRemoved { who }.into());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
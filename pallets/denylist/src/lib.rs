@@ -0,0 +1,155 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Denylist Module
+//!
+//! A module for barring accounts from transacting with the anchors.
+//!
+//! ## Overview
+//!
+//! The Denylist module keeps a set of accounts managed by a governance origin and
+//! implements [`TransactFilter`], so it can be plugged into the `TransactFilter` of
+//! `pallet-vanchor` and `pallet-mixer` to reject deposits and withdrawals in which
+//! any denied account takes part.
+//!
+//! The supported dispatchable functions are documented in the [`Call`] enum.
+//!
+//! ## Interface
+//!
+//! ### Permissioned Functions
+//!
+//! * `add_account`: Adds an account to the denylist. This method requires the `origin` to be
+//!   `ManagerOrigin`.
+//! * `remove_account`: Removes an account from the denylist. This method requires the `origin` to
+//!   be `ManagerOrigin`.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{dispatch::DispatchResult, ensure};
+use webb_primitives::traits::transact_filter::TransactFilter;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin allowed to add and remove accounts
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weightinfo for pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The accounts barred from transacting
+	#[pallet::storage]
+	pub type DeniedAccounts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Account added to the denylist
+		AccountAdded { who: T::AccountId },
+		/// Account removed from the denylist
+		AccountRemoved { who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Account is already on the denylist
+		AlreadyDenied,
+		/// Account is not on the denylist
+		NotDenied,
+		/// An account taking part in the transaction is on the denylist
+		AccountDenied,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		#[pallet::weight(T::WeightInfo::add_account())]
+		#[pallet::call_index(0)]
+		pub fn add_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_denied(&who), Error::<T, I>::AlreadyDenied);
+			DeniedAccounts::<T, I>::insert(&who, ());
+			Self::deposit_event(Event::AccountAdded { who });
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::remove_account())]
+		#[pallet::call_index(1)]
+		pub fn remove_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_denied(&who), Error::<T, I>::NotDenied);
+			DeniedAccounts::<T, I>::remove(&who);
+			Self::deposit_event(Event::AccountRemoved { who });
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn is_denied(who: &T::AccountId) -> bool {
+		DeniedAccounts::<T, I>::contains_key(who)
+	}
+}
+
+impl<T: Config<I>, I: 'static, CurrencyId, Balance>
+	TransactFilter<T::AccountId, CurrencyId, Balance> for Pallet<T, I>
+{
+	fn filter(
+		transactor: &T::AccountId,
+		recipient: &T::AccountId,
+		relayer: &T::AccountId,
+		_asset: CurrencyId,
+		_amount: Balance,
+	) -> DispatchResult {
+		for who in [transactor, recipient, relayer] {
+			ensure!(!Self::is_denied(who), Error::<T, I>::AccountDenied);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,67 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use webb_primitives::AccountId;
+
+fn account(id: u8) -> AccountId {
+	AccountId::new([id; 32])
+}
+
+#[test]
+fn should_add_and_remove_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Denylist::add_account(RuntimeOrigin::root(), account(1)));
+		assert!(Denylist::is_denied(&account(1)));
+		System::assert_last_event(Event::AccountAdded { who: account(1) }.into());
+		assert_noop!(
+			Denylist::add_account(RuntimeOrigin::root(), account(1)),
+			Error::<Test>::AlreadyDenied
+		);
+
+		assert_ok!(Denylist::remove_account(RuntimeOrigin::root(), account(1)));
+		assert!(!Denylist::is_denied(&account(1)));
+		System::assert_last_event(Event::AccountRemoved { who: account(1) }.into());
+		assert_noop!(
+			Denylist::remove_account(RuntimeOrigin::root(), account(1)),
+			Error::<Test>::NotDenied
+		);
+	});
+}
+
+#[test]
+fn should_only_allow_manager_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Denylist::add_account(RuntimeOrigin::signed(account(1)), account(2)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Denylist::add_account(RuntimeOrigin::root(), account(2)));
+		assert_noop!(
+			Denylist::remove_account(RuntimeOrigin::signed(account(1)), account(2)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn should_filter_transactions_with_denied_parties() {
+	new_test_ext().execute_with(|| {
+		let filter = |transactor: u8, recipient: u8, relayer: u8| {
+			<Denylist as TransactFilter<AccountId, u32, u128>>::filter(
+				&account(transactor),
+				&account(recipient),
+				&account(relayer),
+				0,
+				10,
+			)
+		};
+		assert_ok!(filter(1, 2, 3));
+
+		assert_ok!(Denylist::add_account(RuntimeOrigin::root(), account(3)));
+		assert_ok!(filter(1, 2, 4));
+		assert_err!(filter(3, 2, 4), Error::<Test>::AccountDenied);
+		assert_err!(filter(1, 3, 4), Error::<Test>::AccountDenied);
+		assert_err!(filter(1, 2, 3), Error::<Test>::AccountDenied);
+	});
+}
@@ -0,0 +1,81 @@
+// This file is part of Webb.
+
+// Copyright (C) 2022 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_denylist`
+//!
+//! These weights are placeholders estimated by hand, not benchmarked. They must be
+//! replaced by the output of the first benchmark run.
+
+// Command to generate this file:
+// ./target/release/webb-standalone-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet-denylist
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --output=./pallets/denylist/src/weights.rs
+// --template=./.maintain/webb-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_denylist.
+pub trait WeightInfo {
+	fn add_account() -> Weight;
+	fn remove_account() -> Weight;
+}
+
+/// Weight functions for `pallet_denylist`.
+pub struct WebbWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for WebbWeight<T> {
+	// Storage: Denylist DeniedAccounts (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn add_account() -> Weight {
+		Weight::from_ref_time(12_034_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Denylist DeniedAccounts (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn remove_account() -> Weight {
+		Weight::from_ref_time(12_516_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn add_account() -> Weight {
+		Weight::from_ref_time(12_034_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_account() -> Weight {
+		Weight::from_ref_time(12_516_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, ConstU32, IdentityLookup},
+	traits::{BlakeTwo256, ConstBool, ConstU128, ConstU32, ConstU64, IdentityLookup},
 };
 use sp_std::convert::{TryFrom, TryInto};
 use webb_primitives::{field_ops::ArkworksIntoFieldBn254, verifying::CircomVerifierBn254};
@@ -332,6 +332,17 @@ impl pallet_vanchor::Config<VAnchorInstance1> for Test {
 	type ProposalNonce = u32;
 	type VAnchorVerifier = VAnchorVerifier;
 	type KeyStorage = KeyStorage;
+	type BlockAuthor = ();
+	type UnsignedPriority = ConstU64<1_000>;
+	type MaxUnsignedFeePriority = ConstU64<100>;
+	type MinUnsignedFee = ConstU128<1>;
+	type MaxUnsignedTransactsPerBlock = ConstU32<2>;
+	type MaxEncryptedOutputLength = ConstU32<1_024>;
+	type RequireEncryptedOutputEnvelope = ConstBool<false>;
+	type WithdrawalWindowLength = ConstU64<10>;
+	type WithdrawalReleaseDelay = ConstU64<10>;
+	type TransactFilter = ();
+	type Mixer = ();
 	type WeightInfo = ();
 }
 
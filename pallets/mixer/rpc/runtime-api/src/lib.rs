@@ -0,0 +1,40 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+use webb_primitives::{types::mixer::MixerMetadata, ElementTrait};
+
+sp_api::decl_runtime_apis! {
+	pub trait MixerApi<Balance, CurrencyId, E, L>
+	where
+		Balance: Codec,
+		CurrencyId: Codec,
+		E: ElementTrait,
+		L: Codec,
+	{
+		/// Get every mixer with its deposit size and asset
+		fn get_mixers() -> Vec<(u32, MixerMetadata<Balance, CurrencyId>)>;
+		/// Check whether a nullifier hash has already been spent in a mixer
+		fn is_nullifier_used(tree_id: u32, nullifier_hash: E) -> bool;
+		/// Get the number of deposits in a mixer, which is the size of its
+		/// anonymity set, or `None` if the mixer does not exist
+		fn get_leaf_count(tree_id: u32) -> Option<L>;
+	}
+}
@@ -0,0 +1,73 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
+
+#[derive(Debug, thiserror::Error)]
+/// Top-level error type for the RPC handler
+pub enum Error {
+	/// The Mixer RPC endpoint is not ready.
+	#[error("Mixer RPC endpoint not ready")]
+	EndpointNotReady,
+	/// The mixers request failed
+	#[error("Mixer list request failed")]
+	MixersRequestFailure,
+	/// The nullifier check failed
+	#[error("Mixer nullifier check request failed")]
+	NullifierCheckRequestFailure,
+	/// The leaf count request failed
+	#[error("Mixer leaf count request failed")]
+	LeafCountRequestFailure,
+}
+
+/// The error codes returned by jsonrpc.
+pub enum ErrorCode {
+	/// Returned when Mixer RPC endpoint is not ready.
+	NotReady = 1,
+	/// Mixers request failed
+	MixersRequestFailure,
+	/// Nullifier check request failed
+	NullifierCheckRequestFailure,
+	/// Leaf count request failed
+	LeafCountRequestFailure,
+}
+
+impl From<Error> for ErrorCode {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::EndpointNotReady => ErrorCode::NotReady,
+			Error::MixersRequestFailure => ErrorCode::MixersRequestFailure,
+			Error::NullifierCheckRequestFailure => ErrorCode::NullifierCheckRequestFailure,
+			Error::LeafCountRequestFailure => ErrorCode::LeafCountRequestFailure,
+		}
+	}
+}
+
+impl From<Error> for JsonRpseeError {
+	fn from(error: Error) -> Self {
+		let message = error.to_string();
+		let code = ErrorCode::from(error);
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			code as i32,
+			message,
+			None::<()>,
+		)))
+	}
+}
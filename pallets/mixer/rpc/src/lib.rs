@@ -0,0 +1,126 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+mod error;
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_rpc::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_mixer_rpc_runtime_api::MixerApi;
+use webb_primitives::{types::mixer::MixerMetadata, ElementTrait};
+
+/// Mixer RPC methods.
+#[rpc(client, server)]
+pub trait MixerRpcApi<BlockHash, B, CID, E, L> {
+	/// Get the mixers.
+	///
+	/// Returns the tree id of every mixer with its deposit size and asset
+	#[method(name = "mixer_getMixers")]
+	fn get_mixers(&self, at: Option<BlockHash>) -> RpcResult<Vec<(u32, MixerMetadata<B, CID>)>>;
+
+	/// Check whether a nullifier hash has been spent in a mixer.
+	#[method(name = "mixer_isNullifierUsed")]
+	fn is_nullifier_used(
+		&self,
+		tree_id: u32,
+		nullifier_hash: E,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Get the anonymity set size of a mixer.
+	///
+	/// Returns the number of leaves in the mixer tree, or `None` if there is
+	/// no such mixer
+	#[method(name = "mixer_getLeafCount")]
+	fn get_leaf_count(&self, tree_id: u32, at: Option<BlockHash>) -> RpcResult<Option<L>>;
+}
+
+/// A struct that implements the `MixerApi`.
+pub struct MixerClient<C, M> {
+	client: Arc<C>,
+	deny_unsafe: DenyUnsafe,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> MixerClient<C, M> {
+	/// Create new `Mixer` instance with the given reference to the client.
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, B, CID, E, L> MixerRpcApiServer<<Block as BlockT>::Hash, B, CID, E, L>
+	for MixerClient<C, Block>
+where
+	Block: BlockT,
+	B: Encode + Decode,
+	CID: Encode + Decode,
+	E: ElementTrait,
+	L: Encode + Decode,
+	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	C::Api: MixerApi<Block, B, CID, E, L>,
+{
+	fn get_mixers(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u32, MixerMetadata<B, CID>)>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_mixers(at)
+			.map_err(|_| error::Error::MixersRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn is_nullifier_used(
+		&self,
+		tree_id: u32,
+		nullifier_hash: E,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.is_nullifier_used(at, tree_id, nullifier_hash)
+			.map_err(|_| error::Error::NullifierCheckRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_leaf_count(
+		&self,
+		tree_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<L>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_leaf_count(at, tree_id)
+			.map_err(|_| error::Error::LeafCountRequestFailure)
+			.map_err(Into::into)
+	}
+}
@@ -72,12 +72,14 @@ use frame_support::{
 	PalletId,
 };
 use orml_traits::MultiCurrency;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::prelude::*;
 use webb_primitives::{
 	hasher::InstanceHasher,
 	traits::{
 		merkle_tree::{TreeInspector, TreeInterface},
 		mixer::{MixerInspector, MixerInterface},
+		transact_filter::TransactFilter,
 	},
 	verifier::*,
 };
@@ -131,6 +133,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type NativeCurrencyId: Get<CurrencyIdOf<Self, I>>;
 
+		/// Max fee paid to the relayer of a withdrawal
+		#[pallet::constant]
+		type MaxFee: Get<BalanceOf<Self, I>>;
+
+		/// Screens the accounts of a deposit or withdrawal before any state is changed
+		type TransactFilter: TransactFilter<
+			Self::AccountId,
+			CurrencyIdOf<Self, I>,
+			BalanceOf<Self, I>,
+		>;
+
 		/// WeightInfo for pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -191,6 +204,12 @@ pub mod pallet {
 		UnknownRoot,
 		/// No mixer found
 		NoMixerFound,
+		/// Fee exceeds the max fee
+		InvalidFee,
+		/// Fee exceeds the deposit size of the mixer
+		FeeExceedsDeposit,
+		/// Refunds are paid by the relayer, which must submit the withdrawal
+		InvalidRefund,
 	}
 
 	#[pallet::genesis_config]
@@ -268,8 +287,10 @@ pub mod pallet {
 			fee: BalanceOf<T, I>,
 			refund: BalanceOf<T, I>,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
+			let sender = ensure_signed(origin)?;
+			ensure!(refund.is_zero() || sender == relayer, Error::<T, I>::InvalidRefund);
 			<Self as MixerInterface<_, _, _, _, _>>::withdraw(
+				sender,
 				id,
 				&proof_bytes,
 				root,
@@ -305,10 +326,17 @@ impl<T: Config<I>, I: 'static>
 		id: T::TreeId,
 		leaf: T::Element,
 	) -> Result<(), DispatchError> {
+		let mixer = Self::get_mixer(id)?;
+		T::TransactFilter::filter(
+			&depositor,
+			&depositor,
+			&depositor,
+			mixer.asset,
+			mixer.deposit_size,
+		)?;
 		// insert the leaf
 		T::Tree::insert_in_order(id, leaf)?;
 
-		let mixer = Self::get_mixer(id)?;
 		// transfer tokens to the pallet
 		<T as pallet::Config<I>>::Currency::transfer(
 			mixer.asset,
@@ -321,6 +349,7 @@ impl<T: Config<I>, I: 'static>
 	}
 
 	fn withdraw(
+		transactor: T::AccountId,
 		id: T::TreeId,
 		proof_bytes: &[u8],
 		root: T::Element,
@@ -331,6 +360,16 @@ impl<T: Config<I>, I: 'static>
 		refund: BalanceOf<T, I>,
 	) -> Result<(), DispatchError> {
 		let mixer = Self::get_mixer(id)?;
+		T::TransactFilter::filter(
+			&transactor,
+			&recipient,
+			&relayer,
+			mixer.asset,
+			mixer.deposit_size,
+		)?;
+		// The fee is taken out of the deposit
+		ensure!(fee <= T::MaxFee::get(), Error::<T, I>::InvalidFee);
+		ensure!(fee <= mixer.deposit_size, Error::<T, I>::FeeExceedsDeposit);
 		// Check if local root is known
 		ensure!(T::Tree::is_known_root(id, root)?, Error::<T, I>::UnknownRoot);
 		// Check nullifier and add or return `AlreadyRevealedNullifier`
@@ -365,12 +404,30 @@ impl<T: Config<I>, I: 'static>
 		log::info!("verification result: {}", result);
 		ensure!(result, Error::<T, I>::InvalidWithdrawProof);
 
+		// Pay the fee to the relayer and the rest of the deposit to the recipient
+		if !fee.is_zero() {
+			<T as pallet::Config<I>>::Currency::transfer(
+				mixer.asset,
+				&Self::account_id(),
+				&relayer,
+				fee,
+			)?;
+		}
 		<T as pallet::Config<I>>::Currency::transfer(
 			mixer.asset,
 			&Self::account_id(),
 			&recipient,
-			mixer.deposit_size,
+			mixer.deposit_size.saturating_sub(fee),
 		)?;
+		// Send the native gas-refund from the relayer to the recipient
+		if !refund.is_zero() {
+			<T as pallet::Config<I>>::Currency::transfer(
+				T::NativeCurrencyId::get(),
+				&relayer,
+				&recipient,
+				refund,
+			)?;
+		}
 
 		Ok(())
 	}
@@ -379,6 +436,11 @@ impl<T: Config<I>, I: 'static>
 		NullifierHashes::<T, I>::insert(id, nullifier_hash, true);
 		Ok(())
 	}
+
+	fn deposit_info(id: T::TreeId) -> Result<(BalanceOf<T, I>, CurrencyIdOf<T, I>), DispatchError> {
+		let mixer = Self::get_mixer(id)?;
+		Ok((mixer.deposit_size, mixer.asset))
+	}
 }
 
 impl<T: Config<I>, I: 'static>
@@ -420,6 +482,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(mixer.is_some(), Error::<T, I>::NoMixerFound);
 		Ok(mixer.unwrap())
 	}
+
+	/// Lists every mixer with its deposit size and asset
+	pub fn get_mixers() -> Vec<(T::TreeId, MixerMetadata<BalanceOf<T, I>, CurrencyIdOf<T, I>>)> {
+		Mixers::<T, I>::iter().collect()
+	}
 }
 
 /// Truncate and pad 256 bit slice
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, ConstU32, IdentityLookup},
+	traits::{BlakeTwo256, ConstU128, ConstU32, IdentityLookup},
 };
 use sp_std::convert::{TryFrom, TryInto};
 pub use webb_primitives::hasher::{HasherModule, InstanceHasher};
@@ -217,15 +217,39 @@ impl pallet_asset_registry::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub static DeniedAccount: Option<AccountId> = None;
+}
+
+/// Rejects transactions in which `DeniedAccount` takes part.
+pub struct MockTransactFilter;
+impl TransactFilter<AccountId, webb_primitives::AssetId, Balance> for MockTransactFilter {
+	fn filter(
+		transactor: &AccountId,
+		recipient: &AccountId,
+		relayer: &AccountId,
+		_: webb_primitives::AssetId,
+		_: Balance,
+	) -> frame_support::dispatch::DispatchResult {
+		match DeniedAccount::get() {
+			Some(denied) if [transactor, recipient, relayer].contains(&&denied) =>
+				Err(DispatchError::Other("Denied account")),
+			_ => Ok(()),
+		}
+	}
+}
+
 type MixerInstance1 = pallet_mixer::Instance1;
 impl pallet_mixer::Config<MixerInstance1> for Test {
 	type Currency = Currencies;
 	type RuntimeEvent = RuntimeEvent;
 	type NativeCurrencyId = NativeCurrencyId;
+	type MaxFee = ConstU128<5>;
 	type PalletId = MixerPalletId;
 	type Tree = MerkleTree;
 	type Verifier = VerifierPallet;
 	type ArbitraryHasher = Keccak256HasherBn254;
+	type TransactFilter = MockTransactFilter;
 	type WeightInfo = ();
 }
 
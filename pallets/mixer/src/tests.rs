@@ -5,11 +5,14 @@ use frame_benchmarking::account;
 use frame_support::{assert_err, assert_ok, traits::OnInitialize};
 use orml_traits::MultiCurrency;
 use pallet_asset_registry::AssetType;
-use sp_runtime::traits::{One, Zero};
+use sp_runtime::{
+	traits::{One, Zero},
+	DispatchError,
+};
 use sp_std::convert::TryInto;
-use webb_primitives::{merkle_tree::TreeInspector, AccountId, ElementTrait};
+use webb_primitives::{merkle_tree::TreeInspector, mixer::MixerInterface, AccountId, ElementTrait};
 
-use crate::{mock::*, test_utils::*};
+use crate::{mock::*, test_utils::*, types::MixerMetadata};
 
 const SEED: u32 = 0;
 
@@ -78,6 +81,31 @@ fn should_create_new_mixer() {
 	});
 }
 
+#[test]
+fn should_list_mixers_with_their_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HasherPallet::force_set_parameters(
+			RuntimeOrigin::root(),
+			hasher_params().try_into().unwrap()
+		));
+		<MerkleTree as OnInitialize<u64>>::on_initialize(1);
+		assert_ok!(Mixer::create(RuntimeOrigin::root(), 10, 3, 0));
+		let first_tree_id = MerkleTree::next_tree_id() - 1;
+		assert_ok!(Mixer::create(RuntimeOrigin::root(), 20, 3, 0));
+		let second_tree_id = MerkleTree::next_tree_id() - 1;
+
+		let mut mixers = Mixer::get_mixers();
+		mixers.sort_by_key(|(tree_id, _)| *tree_id);
+		assert_eq!(
+			mixers,
+			vec![
+				(first_tree_id, MixerMetadata { deposit_size: 10, asset: 0 }),
+				(second_tree_id, MixerMetadata { deposit_size: 20, asset: 0 }),
+			]
+		);
+	});
+}
+
 #[test]
 fn should_be_able_to_deposit() {
 	new_test_ext().execute_with(|| {
@@ -390,7 +418,7 @@ fn mixer_should_fail_with_when_fee_submitted_is_changed() {
 				nullifier_hash_element,
 				recipient_account_id,
 				relayer_account_id,
-				100u128,
+				1u128,
 				refund_value,
 			),
 			crate::Error::<Test, Instance1>::InvalidWithdrawProof
@@ -591,3 +619,170 @@ fn deposit_with_non_native_asset_should_work() {
 		);
 	});
 }
+
+#[test]
+fn transact_filter_should_block_denied_accounts() {
+	new_test_ext().execute_with(|| {
+		let curve = Curve::Bn254;
+		let pk_bytes = setup_environment(curve);
+		let deposit_size = One::one();
+		assert_ok!(Mixer::create(RuntimeOrigin::root(), deposit_size, 30, 0));
+		let tree_id = MerkleTree::next_tree_id() - 1;
+		let sender_account_id = account::<AccountId>("", 1, SEED);
+		let recipient_account_id = account::<AccountId>("", 2, SEED);
+		let relayer_account_id = account::<AccountId>("", 0, SEED);
+
+		let recipient_bytes = crate::truncate_and_pad(&recipient_account_id.encode()[..]);
+		let relayer_bytes = crate::truncate_and_pad(&relayer_account_id.encode()[..]);
+		let (proof_bytes, root_element, nullifier_hash_element, leaf_element) =
+			setup_zk_circuit(curve, recipient_bytes, relayer_bytes, pk_bytes, 0, 0);
+
+		// Denied depositors cannot deposit
+		DeniedAccount::set(Some(sender_account_id.clone()));
+		assert_err!(
+			Mixer::deposit(RuntimeOrigin::signed(sender_account_id.clone()), tree_id, leaf_element),
+			DispatchError::Other("Denied account")
+		);
+		assert_eq!(MerkleTree::trees(tree_id).unwrap().leaf_count, 0);
+		DeniedAccount::set(None);
+		assert_ok!(Mixer::deposit(
+			RuntimeOrigin::signed(sender_account_id.clone()),
+			tree_id,
+			leaf_element
+		));
+
+		// Denied recipients cannot receive withdrawals
+		DeniedAccount::set(Some(recipient_account_id.clone()));
+		assert_err!(
+			Mixer::withdraw(
+				RuntimeOrigin::signed(sender_account_id.clone()),
+				tree_id,
+				proof_bytes.clone(),
+				root_element,
+				nullifier_hash_element,
+				recipient_account_id.clone(),
+				relayer_account_id.clone(),
+				0,
+				0,
+			),
+			DispatchError::Other("Denied account")
+		);
+
+		// Other pallets withdrawing through the interface are filtered as well
+		DeniedAccount::set(Some(sender_account_id.clone()));
+		assert_err!(
+			<Mixer as MixerInterface<_, _, _, _, _>>::withdraw(
+				sender_account_id,
+				tree_id,
+				&proof_bytes,
+				root_element,
+				nullifier_hash_element,
+				recipient_account_id,
+				relayer_account_id,
+				0,
+				0,
+			),
+			DispatchError::Other("Denied account")
+		);
+		DeniedAccount::set(None);
+	});
+}
+
+#[test]
+fn mixer_should_pay_fee_to_relayer_and_refund_to_recipient() {
+	new_test_ext().execute_with(|| {
+		let curve = Curve::Bn254;
+		let pk_bytes = setup_environment(curve);
+		let deposit_size = 10;
+		assert_ok!(Mixer::create(RuntimeOrigin::root(), deposit_size, 30, 0));
+		let tree_id = MerkleTree::next_tree_id() - 1;
+		let sender_account_id = account::<AccountId>("", 1, SEED);
+		let recipient_account_id = account::<AccountId>("", 2, SEED);
+		let relayer_account_id = account::<AccountId>("", 3, SEED);
+		let fee_value = 3;
+		let refund_value = 2;
+
+		let recipient_bytes = crate::truncate_and_pad(&recipient_account_id.encode()[..]);
+		let relayer_bytes = crate::truncate_and_pad(&relayer_account_id.encode()[..]);
+		let (proof_bytes, root_element, nullifier_hash_element, leaf_element) = setup_zk_circuit(
+			curve,
+			recipient_bytes,
+			relayer_bytes,
+			pk_bytes,
+			fee_value,
+			refund_value,
+		);
+		assert_ok!(Mixer::deposit(
+			RuntimeOrigin::signed(sender_account_id.clone()),
+			tree_id,
+			leaf_element
+		));
+
+		// Only the relayer can pay a refund
+		assert_err!(
+			Mixer::withdraw(
+				RuntimeOrigin::signed(sender_account_id),
+				tree_id,
+				proof_bytes.clone(),
+				root_element,
+				nullifier_hash_element,
+				recipient_account_id.clone(),
+				relayer_account_id.clone(),
+				fee_value,
+				refund_value,
+			),
+			crate::Error::<Test, Instance1>::InvalidRefund
+		);
+
+		let recipient_balance_before = Balances::free_balance(recipient_account_id.clone());
+		let relayer_balance_before = Balances::free_balance(relayer_account_id.clone());
+		assert_ok!(Mixer::withdraw(
+			RuntimeOrigin::signed(relayer_account_id.clone()),
+			tree_id,
+			proof_bytes,
+			root_element,
+			nullifier_hash_element,
+			recipient_account_id.clone(),
+			relayer_account_id.clone(),
+			fee_value,
+			refund_value,
+		));
+		assert_eq!(
+			Balances::free_balance(recipient_account_id),
+			recipient_balance_before + deposit_size - fee_value + refund_value
+		);
+		assert_eq!(
+			Balances::free_balance(relayer_account_id),
+			relayer_balance_before + fee_value - refund_value
+		);
+	});
+}
+
+#[test]
+fn mixer_should_reject_fees_over_deposit_or_max_fee() {
+	new_test_ext().execute_with(|| {
+		let _ = setup_environment(Curve::Bn254);
+		let deposit_size = 2;
+		assert_ok!(Mixer::create(RuntimeOrigin::root(), deposit_size, 30, 0));
+		let tree_id = MerkleTree::next_tree_id() - 1;
+		let sender_account_id = account::<AccountId>("", 1, SEED);
+		let recipient_account_id = account::<AccountId>("", 2, SEED);
+		let relayer_account_id = account::<AccountId>("", 3, SEED);
+		let withdraw = |fee: u128| {
+			Mixer::withdraw(
+				RuntimeOrigin::signed(sender_account_id.clone()),
+				tree_id,
+				vec![],
+				Element::from_bytes(&[0u8; 32]),
+				Element::from_bytes(&[0u8; 32]),
+				recipient_account_id.clone(),
+				relayer_account_id.clone(),
+				fee,
+				0,
+			)
+		};
+
+		assert_err!(withdraw(3), crate::Error::<Test, Instance1>::FeeExceedsDeposit);
+		assert_err!(withdraw(6), crate::Error::<Test, Instance1>::InvalidFee);
+	});
+}
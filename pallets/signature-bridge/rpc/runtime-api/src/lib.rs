@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait SignatureBridgeApi<ChainId, ProposalNonce, MaintainerNonce, BlockNumber>
+	where
+		ChainId: Codec,
+		ProposalNonce: Codec,
+		MaintainerNonce: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get the public keys of the active maintainer(s)
+		fn get_maintainers() -> Vec<Vec<u8>>;
+		/// Get the number of maintainer signatures a proposal needs
+		fn get_threshold() -> u32;
+		/// Get the aggregate Schnorr key of the maintainer set, if any
+		fn get_aggregate_key() -> Option<Vec<u8>>;
+		/// Get the nonce of the last maintainer update
+		fn get_maintainer_nonce() -> MaintainerNonce;
+		/// Get the nonce of the last resource set with a signature
+		fn get_proposal_nonce() -> ProposalNonce;
+		/// Get every whitelisted chain with its transaction count
+		fn get_whitelisted_chains() -> Vec<(ChainId, ProposalNonce)>;
+		/// Get every registered resource ID with the nonce of its last executed proposal
+		fn get_resources() -> Vec<([u8; 32], ProposalNonce)>;
+		/// Get the block at which the proposal with the given keccak256 hash was executed,
+		/// or `None` if it was never executed or has been pruned from the execution history
+		fn get_proposal_execution_block(proposal_hash: [u8; 32]) -> Option<BlockNumber>;
+	}
+}
@@ -0,0 +1,85 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
+
+#[derive(Debug, thiserror::Error)]
+/// Top-level error type for the RPC handler
+pub enum Error {
+	/// The Signature Bridge RPC endpoint is not ready.
+	#[error("Signature Bridge RPC endpoint not ready")]
+	EndpointNotReady,
+	/// The maintainer request failed
+	#[error("Signature Bridge maintainer request failed")]
+	MaintainerRequestFailure,
+	/// The nonces request failed
+	#[error("Signature Bridge nonces request failed")]
+	NoncesRequestFailure,
+	/// The whitelisted chains request failed
+	#[error("Signature Bridge whitelisted chains request failed")]
+	ChainsRequestFailure,
+	/// The resources request failed
+	#[error("Signature Bridge resources request failed")]
+	ResourcesRequestFailure,
+	/// The executed proposal check failed
+	#[error("Signature Bridge executed proposal check request failed")]
+	ExecutedProposalRequestFailure,
+}
+
+/// The error codes returned by jsonrpc.
+pub enum ErrorCode {
+	/// Returned when Signature Bridge RPC endpoint is not ready.
+	NotReady = 1,
+	/// Maintainer request failed
+	MaintainerRequestFailure,
+	/// Nonces request failed
+	NoncesRequestFailure,
+	/// Whitelisted chains request failed
+	ChainsRequestFailure,
+	/// Resources request failed
+	ResourcesRequestFailure,
+	/// Executed proposal check request failed
+	ExecutedProposalRequestFailure,
+}
+
+impl From<Error> for ErrorCode {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::EndpointNotReady => ErrorCode::NotReady,
+			Error::MaintainerRequestFailure => ErrorCode::MaintainerRequestFailure,
+			Error::NoncesRequestFailure => ErrorCode::NoncesRequestFailure,
+			Error::ChainsRequestFailure => ErrorCode::ChainsRequestFailure,
+			Error::ResourcesRequestFailure => ErrorCode::ResourcesRequestFailure,
+			Error::ExecutedProposalRequestFailure => ErrorCode::ExecutedProposalRequestFailure,
+		}
+	}
+}
+
+impl From<Error> for JsonRpseeError {
+	fn from(error: Error) -> Self {
+		let message = error.to_string();
+		let code = ErrorCode::from(error);
+		JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			code as i32,
+			message,
+			None::<()>,
+		)))
+	}
+}
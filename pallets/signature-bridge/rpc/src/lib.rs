@@ -0,0 +1,213 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::unnecessary_mut_passed)]
+
+mod error;
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_rpc::DenyUnsafe;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_signature_bridge_rpc_runtime_api::SignatureBridgeApi;
+
+/// Signature Bridge RPC methods.
+#[rpc(client, server)]
+pub trait SignatureBridgeRpcApi<BlockHash, ChainId, ProposalNonce, MaintainerNonce, BlockNumber> {
+	/// Get the public keys of the active maintainer(s).
+	#[method(name = "signatureBridge_getMaintainers")]
+	fn get_maintainers(&self, at: Option<BlockHash>) -> RpcResult<Vec<Bytes>>;
+
+	/// Get the number of maintainer signatures a proposal needs.
+	#[method(name = "signatureBridge_getThreshold")]
+	fn get_threshold(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Get the aggregate Schnorr key of the maintainer set, if any.
+	#[method(name = "signatureBridge_getAggregateKey")]
+	fn get_aggregate_key(&self, at: Option<BlockHash>) -> RpcResult<Option<Bytes>>;
+
+	/// Get the nonce of the last maintainer update.
+	#[method(name = "signatureBridge_getMaintainerNonce")]
+	fn get_maintainer_nonce(&self, at: Option<BlockHash>) -> RpcResult<MaintainerNonce>;
+
+	/// Get the nonce of the last resource set with a signature.
+	#[method(name = "signatureBridge_getProposalNonce")]
+	fn get_proposal_nonce(&self, at: Option<BlockHash>) -> RpcResult<ProposalNonce>;
+
+	/// Get the whitelisted chains.
+	///
+	/// Returns every whitelisted chain ID with its transaction count
+	#[method(name = "signatureBridge_getWhitelistedChains")]
+	fn get_whitelisted_chains(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ChainId, ProposalNonce)>>;
+
+	/// Get the registered resources.
+	///
+	/// Returns every resource ID with the nonce of its last executed proposal
+	#[method(name = "signatureBridge_getResources")]
+	fn get_resources(&self, at: Option<BlockHash>) -> RpcResult<Vec<(H256, ProposalNonce)>>;
+
+	/// Check whether a proposal has been executed.
+	///
+	/// Returns the block at which the proposal with the given keccak256 hash
+	/// was executed, or `None` if it was never executed or is older than the
+	/// execution history kept by the bridge
+	#[method(name = "signatureBridge_getProposalExecutionBlock")]
+	fn get_proposal_execution_block(
+		&self,
+		proposal_hash: H256,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlockNumber>>;
+}
+
+/// A struct that implements the `SignatureBridgeApi`.
+pub struct SignatureBridgeClient<C, M> {
+	client: Arc<C>,
+	deny_unsafe: DenyUnsafe,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> SignatureBridgeClient<C, M> {
+	/// Create new `SignatureBridge` instance with the given reference to the client.
+	pub fn new(client: Arc<C>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, ChainId, ProposalNonce, MaintainerNonce, BlockNumber>
+	SignatureBridgeRpcApiServer<
+		<Block as BlockT>::Hash,
+		ChainId,
+		ProposalNonce,
+		MaintainerNonce,
+		BlockNumber,
+	> for SignatureBridgeClient<C, Block>
+where
+	Block: BlockT,
+	ChainId: Codec,
+	ProposalNonce: Codec,
+	MaintainerNonce: Codec,
+	BlockNumber: Codec,
+	C: HeaderBackend<Block> + ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	C::Api: SignatureBridgeApi<Block, ChainId, ProposalNonce, MaintainerNonce, BlockNumber>,
+{
+	fn get_maintainers(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<Bytes>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_maintainers(at)
+			.map(|keys| keys.into_iter().map(Into::into).collect())
+			.map_err(|_| error::Error::MaintainerRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_threshold(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_threshold(at)
+			.map_err(|_| error::Error::MaintainerRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_aggregate_key(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<Bytes>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_aggregate_key(at)
+			.map(|key| key.map(Into::into))
+			.map_err(|_| error::Error::MaintainerRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_maintainer_nonce(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<MaintainerNonce> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_maintainer_nonce(at)
+			.map_err(|_| error::Error::NoncesRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_proposal_nonce(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<ProposalNonce> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_proposal_nonce(at)
+			.map_err(|_| error::Error::NoncesRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_whitelisted_chains(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(ChainId, ProposalNonce)>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_whitelisted_chains(at)
+			.map_err(|_| error::Error::ChainsRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_resources(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(H256, ProposalNonce)>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_resources(at)
+			.map(|resources| {
+				resources.into_iter().map(|(r_id, nonce)| (H256(r_id), nonce)).collect()
+			})
+			.map_err(|_| error::Error::ResourcesRequestFailure)
+			.map_err(Into::into)
+	}
+
+	fn get_proposal_execution_block(
+		&self,
+		proposal_hash: H256,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BlockNumber>> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_proposal_execution_block(at, proposal_hash.0)
+			.map_err(|_| error::Error::ExecutedProposalRequestFailure)
+			.map_err(Into::into)
+	}
+}
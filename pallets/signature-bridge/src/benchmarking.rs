@@ -17,13 +17,17 @@
 
 //! Signature pallet benchmarking.
 use super::*;
+use crate::types::{
+	BatchedProposal, BridgeStatusUpdate, MaintainerSet, QueuedProposal, PROPOSAL_BATCH_TAG,
+	SET_MAINTAINERS_TAG, SET_MAINTAINER_TAG, SET_TYPED_MAINTAINER_TAG, UPDATE_BRIDGE_STATUS_TAG,
+};
 use frame_benchmarking::{
 	benchmarks_instance_pallet, impl_benchmark_test_suite, whitelisted_caller,
 };
 use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 use sp_io::{
-	crypto::{ecdsa_generate, ecdsa_sign_prehashed},
+	crypto::{ecdsa_generate, ecdsa_sign_prehashed, sr25519_generate},
 	hashing::keccak_256,
 };
 use sp_runtime::key_types::DUMMY;
@@ -44,15 +48,38 @@ pub fn generate_maintainer_signatures<T: Config<I>, I: 'static>() -> (Vec<u8>, V
 	let new_maintainer = ecdsa_generate(DUMMY, None);
 	let old_maintainer = ecdsa_generate(DUMMY, None);
 	let old_maintainer_key = set_maintainer_on_chain::<T, I>(old_maintainer);
-	let mut message = vec![];
+	let mut message = SET_MAINTAINER_TAG.to_vec();
 	let nonce = 1u32.to_be_bytes();
 	message.extend_from_slice(&nonce);
-	message.extend_from_slice(&new_maintainer.encode());
+	message.extend_from_slice(
+		&libsecp256k1::PublicKey::parse_compressed(&new_maintainer.0)
+			.unwrap()
+			.serialize()[1..],
+	);
 	let hash = keccak_256(&message);
 	let signature = ecdsa_sign_prehashed(DUMMY, &old_maintainer, &hash).unwrap();
 	(old_maintainer_key, message, signature.encode())
 }
 
+/// Helper function to build a maintainer set with `n` fresh ECDSA keys and a threshold of `n`
+fn make_maintainer_set<T: Config<I>, I: 'static>(
+	n: u32,
+) -> (Vec<sp_core::ecdsa::Public>, MaintainerSetOf<T, I>) {
+	let keys: Vec<_> = (0..n).map(|_| ecdsa_generate(DUMMY, None)).collect();
+	let members: Vec<BoundedVec<u8, T::MaxStringLength>> = keys
+		.iter()
+		.map(|key| {
+			libsecp256k1::PublicKey::parse_compressed(&key.0).unwrap().serialize()[1..]
+				.to_vec()
+				.try_into()
+				.unwrap()
+		})
+		.collect();
+	let set =
+		MaintainerSet { members: members.try_into().unwrap(), threshold: n, aggregate_key: None };
+	(keys, set)
+}
+
 /// Helper function to generate proposal data
 fn make_proposal_data(encoded_r_id: Vec<u8>, nonce: [u8; 4], encoded_call: Vec<u8>) -> Vec<u8> {
 	let mut prop_data = encoded_r_id;
@@ -172,6 +199,165 @@ benchmarks_instance_pallet! {
 	verify {
 		assert_last_event::<T, I>(Event::ProposalSucceeded{chain_id : src_id.into(), proposal_nonce : 1_u32.into()}.into());
 	}
+
+	set_maintainers {
+		let caller: T::AccountId = whitelisted_caller();
+		let max = T::MaxMaintainers::get().min(8);
+
+		// an active set whose full threshold signs the handoff
+		let (old_keys, old_set) = make_maintainer_set::<T, I>(max);
+		Maintainers::<T, I>::put(old_set);
+		let (_, new_set) = make_maintainer_set::<T, I>(max);
+		let mut message = SET_MAINTAINERS_TAG.to_vec();
+		message.extend_from_slice(&1u32.to_be_bytes());
+		message.extend_from_slice(&new_set.encode());
+		let hash = keccak_256(&message);
+		let signature: Vec<u8> = old_keys
+			.iter()
+			.flat_map(|key| ecdsa_sign_prehashed(DUMMY, key, &hash).unwrap().encode())
+			.collect();
+	}: _(RawOrigin::Signed(caller), message.try_into().unwrap(), signature.try_into().unwrap())
+	verify {
+		assert_last_event::<T, I>(Event::MaintainersSet{maintainers: new_set}.into());
+	}
+
+	force_set_maintainers {
+		let nonce: T::MaintainerNonce = T::MaintainerNonce::from(0u32);
+		let (_, maintainers) = make_maintainer_set::<T, I>(T::MaxMaintainers::get().min(8));
+	}: _(RawOrigin::Root, nonce, maintainers.clone())
+	verify {
+		assert_last_event::<T, I>(Event::MaintainersSet{maintainers}.into());
+	}
+
+	set_typed_maintainer {
+		let caller: T::AccountId = whitelisted_caller();
+		let old_maintainer = ecdsa_generate(DUMMY, None);
+		let old_maintainer_key = set_maintainer_on_chain::<T, I>(old_maintainer);
+		let new_maintainer = sr25519_generate(DUMMY, None);
+		let mut message = SET_TYPED_MAINTAINER_TAG.to_vec();
+		message.extend_from_slice(&1u32.to_be_bytes());
+		message.extend_from_slice(&KeyType::Sr25519.encode());
+		message.extend_from_slice(&new_maintainer.0);
+		let hash = keccak_256(&message);
+		let signature = ecdsa_sign_prehashed(DUMMY, &old_maintainer, &hash).unwrap();
+	}: _(RawOrigin::Signed(caller), message.try_into().unwrap(), signature.encode().try_into().unwrap())
+	verify {
+		assert_last_event::<T, I>(Event::MaintainerSet{old_maintainer: old_maintainer_key.try_into().unwrap(), new_maintainer: new_maintainer.0.to_vec().try_into().unwrap()}.into());
+	}
+
+	force_set_typed_maintainer {
+		let nonce: T::MaintainerNonce = T::MaintainerNonce::from(0u32);
+		let new_maintainer: BoundedVec<u8, T::MaxStringLength> = sr25519_generate(DUMMY, None).0.to_vec().try_into().unwrap();
+	}: _(RawOrigin::Root, nonce, KeyType::Sr25519, new_maintainer.clone())
+	verify {
+		assert_last_event::<T, I>(Event::MaintainerSet{old_maintainer: Default::default(), new_maintainer}.into());
+	}
+
+	execute_proposal_batch {
+		let n in 1 .. T::MaxProposalsPerBatch::get();
+		let caller: T::AccountId = whitelisted_caller();
+
+		// set a new maintainer
+		let maintainer = ecdsa_generate(DUMMY, None);
+		set_maintainer_on_chain::<T, I>(maintainer);
+
+		// whitelist chain
+		let src_id = get_chain_id();
+		let _ = crate::Pallet::<T,I>::whitelist_chain(RawOrigin::Root.into(), src_id.into());
+
+		// prepare one proposal per resource
+		let proposals: Vec<Vec<u8>> = (0..n).map(|tree_id| {
+			let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id }).into();
+			let _ = crate::Pallet::<T,I>::set_resource(RawOrigin::Root.into(), r_id);
+			let call : <T as pallet::Config<I>>::Proposal = frame_system::Call::<T>::remark { remark: vec![10] }.into();
+			make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 1u8], call.encode())
+		}).collect();
+		let (root, proofs) = crate::Pallet::<T, I>::build_proposal_batch(&proposals);
+		let batch: Vec<BatchedProposalOf<T, I>> = proposals
+			.into_iter()
+			.zip(proofs)
+			.map(|(proposal_data, proof)| BatchedProposal { proposal_data: proposal_data.try_into().unwrap(), proof: proof.try_into().unwrap() })
+			.collect();
+		let signature = ecdsa_sign_prehashed(DUMMY, &maintainer, &keccak_256(&[PROPOSAL_BATCH_TAG, &root[..]].concat())).unwrap();
+	}: _(RawOrigin::Signed(caller), src_id.into(), batch.try_into().unwrap(), root, signature.encode().try_into().unwrap())
+	verify {
+		assert_last_event::<T, I>(Event::ProposalBatchExecuted{chain_id: src_id.into(), root, failed: 0}.into());
+	}
+
+	execute_queued_proposal {
+		let caller: T::AccountId = whitelisted_caller();
+		let src_id = get_chain_id();
+		let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 }).into();
+		let call : <T as pallet::Config<I>>::Proposal = frame_system::Call::<T>::remark { remark: vec![10] }.into();
+		let proposal_data = make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 1u8], call.encode());
+		let proposal_hash = keccak_256(&proposal_data);
+		let weight_limit = Pallet::<T, I>::proposal_call_weight(&proposal_data);
+		QueuedProposals::<T, I>::insert(proposal_hash, QueuedProposal {
+			src_id: src_id.into(),
+			proposal_data: proposal_data.try_into().unwrap(),
+			execute_after: 1u32.into(),
+		});
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+	}: _(RawOrigin::Signed(caller), proposal_hash, weight_limit)
+	verify {
+		assert!(!QueuedProposals::<T, I>::contains_key(proposal_hash));
+	}
+
+	cancel_queued_proposal {
+		let src_id = get_chain_id();
+		let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 }).into();
+		let call : <T as pallet::Config<I>>::Proposal = frame_system::Call::<T>::remark { remark: vec![10] }.into();
+		let proposal_data = make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 1u8], call.encode());
+		let proposal_hash = keccak_256(&proposal_data);
+		QueuedProposals::<T, I>::insert(proposal_hash, QueuedProposal {
+			src_id: src_id.into(),
+			proposal_data: proposal_data.try_into().unwrap(),
+			execute_after: 1u32.into(),
+		});
+	}: _(RawOrigin::Root, proposal_hash)
+	verify {
+		assert_last_event::<T, I>(Event::QueuedProposalCancelled{ proposal_hash }.into());
+	}
+
+	remove_chain {
+		let src_id = get_chain_id();
+		let _ = crate::Pallet::<T,I>::whitelist_chain(RawOrigin::Root.into(), src_id.into());
+	}: _(RawOrigin::Root, src_id.into())
+	verify {
+		assert_last_event::<T, I>(Event::ChainRemoved{ chain_id: src_id.into() }.into());
+	}
+
+	pause_resource {
+		let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 }).into();
+		let _ = crate::Pallet::<T,I>::set_resource(RawOrigin::Root.into(), r_id);
+	}: _(RawOrigin::Root, r_id)
+	verify {
+		assert_last_event::<T, I>(Event::ResourcePaused{ resource_id: r_id }.into());
+	}
+
+	unpause_resource {
+		let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 }).into();
+		let _ = crate::Pallet::<T,I>::set_resource(RawOrigin::Root.into(), r_id);
+		let _ = crate::Pallet::<T,I>::pause_resource(RawOrigin::Root.into(), r_id);
+	}: _(RawOrigin::Root, r_id)
+	verify {
+		assert_last_event::<T, I>(Event::ResourceUnpaused{ resource_id: r_id }.into());
+	}
+
+	update_bridge_status {
+		let caller: T::AccountId = whitelisted_caller();
+		let maintainer = ecdsa_generate(DUMMY, None);
+		set_maintainer_on_chain::<T, I>(maintainer);
+		let r_id : ResourceId = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 }).into();
+		let _ = crate::Pallet::<T,I>::set_resource(RawOrigin::Root.into(), r_id);
+		let mut message = UPDATE_BRIDGE_STATUS_TAG.to_vec();
+		message.extend_from_slice(&1u32.to_be_bytes());
+		message.extend_from_slice(&BridgeStatusUpdate::<T::ChainId>::PauseResource(r_id).encode());
+		let signature = ecdsa_sign_prehashed(DUMMY, &maintainer, &keccak_256(&message)).unwrap();
+	}: _(RawOrigin::Signed(caller), message.try_into().unwrap(), signature.encode().try_into().unwrap())
+	verify {
+		assert_last_event::<T, I>(Event::ResourcePaused{ resource_id: r_id }.into());
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -32,18 +32,76 @@
 //!
 //! * `force_set_maintainer`: Forcefully set the maintainer. This method requires the `origin` to be
 //!   [T::AdminOrigin].
+//! * `force_set_maintainers`: Forcefully set a threshold maintainer set. This method requires the
+//!   `origin` to be [T::AdminOrigin].
+//! * `force_set_typed_maintainer`: Forcefully set a maintainer key of a given [KeyType]. This
+//!   method requires the `origin` to be [T::AdminOrigin].
 //! * `set_resource`: Stores a method name on chain under an associated resource ID. This method
 //!   requires the `origin` to be [T::AdminOrigin].
 //! * `remove_resource`: Removes a resource ID from the resource mapping. This method requires the
 //!   `origin` to be [T::AdminOrigin].
 //! * `whitelist_chain`: Enables a chain ID as a source or destination for a bridge transfer. This
 //!   method requires the `origin` to be [T::AdminOrigin].
+//! * `cancel_queued_proposal`: Cancels a sensitive proposal during its timelock. This method
+//!   requires the `origin` to be [T::AdminOrigin].
+//! * `remove_chain`: Stops accepting proposals from a whitelisted chain. This method requires the
+//!   `origin` to be [T::AdminOrigin].
+//! * `pause_resource`: Stops accepting proposals for a resource without removing it. This method
+//!   requires the `origin` to be [T::AdminOrigin].
+//! * `unpause_resource`: Resumes accepting proposals for a paused resource. This method requires
+//!   the `origin` to be [T::AdminOrigin].
 //!
 //! ### Permissionless Functions
 //!
 //! * `execute_proposal`: Executes proposal if the proposal data is well-formed and signed by DKG
 //!   (see the function below for more documentation)
+//! * `execute_proposal_batch`: Executes a batch of proposals under a single signature over their
+//!   Merkle root, reporting the proposals that fail without reverting the others.
+//! * `execute_queued_proposal`: Executes a sensitive proposal once its timelock has expired.
+//! * `update_bridge_status`: Removes a chain, or pauses or unpauses a resource, with a message
+//!   signed by the maintainer(s).
 //! * `set_maintainer`: Sets the maintainer.
+//! * `set_maintainers`: Hands off to a new threshold maintainer set.
+//! * `set_typed_maintainer`: Hands off to a maintainer key of a given [KeyType].
+//!
+//! ### Threshold maintainers
+//!
+//! Instead of a single key, the bridge can be governed by a set of maintainer keys together
+//! with a threshold. While a set is active, a signature over a message is accepted if it is
+//! either `threshold` concatenated 65-byte ECDSA signatures from distinct members, or a 64-byte
+//! BIP-340 Schnorr signature of the set's aggregate key (as produced by a FROST signer set).
+//!
+//! ### Maintainer key types
+//!
+//! A single maintainer key is tagged with a [KeyType]. Keys set through `set_maintainer`,
+//! `force_set_maintainer` or the [SetMaintainer] hook are ECDSA keys checked with
+//! [T::SignatureVerifier]. The typed calls also accept sr25519 and ed25519 keys, which sign the
+//! raw message, and BIP-340 Schnorr keys, which sign its keccak256 hash.
+//!
+//! By default ECDSA keys sign the bare keccak256 hash of a message, which carries no domain. A
+//! runtime can instead set [T::SignatureVerifier] to `Eip191SignatureVerifier`, for messages
+//! signed with Ethereum's `personal_sign`, or to `Eip712SignatureVerifier`, for messages signed
+//! as typed data in a domain holding the chain ID type and the bridge instance. Signatures made
+//! for another protocol or another bridge are then rejected.
+//!
+//! ### Fees
+//!
+//! Submitters pay no fees for proposals that execute successfully, so that relayers have no
+//! reason to withhold them. Proposals that fail are charged, which keeps spamming costly.
+//!
+//! ### Timelocked proposals
+//!
+//! Proposals whose call matches [T::SensitiveProposalFilter] are not dispatched when they are
+//! executed. They are queued instead, and can be executed by anyone [T::ProposalDelay] blocks
+//! later, unless [T::AdminOrigin] cancels them in the meantime. A zero delay disables the queue.
+//!
+//! ### Typed proposals
+//!
+//! Proposals are made of a 40 byte header, holding the resource ID, a function signature and the
+//! nonce, followed by a body. Typed proposals of the `webb_proposals` crate, such as
+//! `AnchorUpdateProposal`, are mapped to the handler call that executes them by
+//! [T::ProposalDecoder], so that they are signed in the same format for every chain. The body of
+//! any other proposal is a SCALE encoded call.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -56,34 +114,56 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod types;
 mod weights;
-use codec::{self, Decode, Encode, EncodeLike, MaxEncodedLen};
+use codec::{self, Decode, DecodeAll, Encode, EncodeLike, MaxEncodedLen};
 use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::{ensure, DispatchResultWithPostInfo},
-	traits::{EnsureOrigin, Get},
+	storage::with_storage_layer,
+	traits::{Contains, ContainsPair, EnsureOrigin, Get},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::{self as system, ensure_root};
 pub use pallet::*;
 use scale_info::TypeInfo;
+use sp_io::hashing::keccak_256;
 use sp_runtime::{
-	traits::{AccountIdConversion, Dispatchable},
+	traits::{AccountIdConversion, Dispatchable, Saturating, Zero},
 	DispatchError, RuntimeDebug,
 };
 use sp_std::{convert::TryInto, prelude::*};
+use types::{
+	BatchedProposal, BridgeStatusUpdate, MaintainerSet, QueuedProposal, PROPOSAL_BATCH_TAG,
+	SET_MAINTAINERS_TAG, SET_MAINTAINER_TAG, SET_TYPED_MAINTAINER_TAG, UPDATE_BRIDGE_STATUS_TAG,
+};
 use webb_primitives::{
-	signature_bridge::SetMaintainer, signing::SigningSystem, utils::compute_chain_id_type,
-	webb_proposals::ResourceId,
+	signature_bridge::{SetMaintainer, TypedProposalDecoder, VerifyMaintainerSignature},
+	signing::{
+		ecdsa, schnorr, Ed25519Verifier, KeyType, SchnorrVerifier, SigningSystem, Sr25519Verifier,
+	},
+	utils::compute_chain_id_type,
+	webb_proposals::{ProposalHeader, ResourceId},
 };
 pub use weights::WeightInfo;
 
+pub type MaintainerSetOf<T, I> =
+	MaintainerSet<<T as Config<I>>::MaxStringLength, <T as Config<I>>::MaxMaintainers>;
+pub type QueuedProposalOf<T, I> = QueuedProposal<
+	<T as Config<I>>::ChainId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config<I>>::MaxStringLength,
+>;
+pub type BatchedProposalOf<T, I> =
+	BatchedProposal<<T as Config<I>>::MaxStringLength, <T as Config<I>>::MaxProposalsPerBatch>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::{
 		dispatch::{DispatchResultWithPostInfo, Dispatchable, GetDispatchInfo},
 		pallet_prelude::*,
-		traits::Contains,
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
@@ -104,13 +184,19 @@ pub mod pallet {
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Proposed dispatchable call
 		type Proposal: Parameter
-			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ EncodeLike
 			+ Decode
 			+ GetDispatchInfo;
 		/// Call filter for proposals
 		type SetResourceProposalFilter: Contains<Self::Proposal>;
 		type ExecuteProposalFilter: Contains<Self::Proposal>;
+		/// Checks that a proposal call targets the handler and tree of its resource ID
+		type ProposalResourceMatcher: ContainsPair<ResourceId, Self::Proposal>;
+		/// Proposal calls that are timelocked before being dispatched
+		type SensitiveProposalFilter: Contains<Self::Proposal>;
+		/// Maps typed `webb_proposals` proposals to the call that executes them
+		type ProposalDecoder: TypedProposalDecoder<Self::Proposal>;
 		/// ChainID for anchor edges
 		type ChainId: Encode
 			+ Decode
@@ -154,11 +240,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalLifetime: Get<Self::BlockNumber>;
 
+		/// The number of executed proposals that are remembered, older ones are pruned.
+		/// With 0, executed proposals are not recorded at all.
+		#[pallet::constant]
+		type ExecutedProposalHistorySize: Get<u32>;
+
+		/// The number of blocks sensitive proposals are timelocked for
+		#[pallet::constant]
+		type ProposalDelay: Get<Self::BlockNumber>;
+
 		#[pallet::constant]
 		type BridgeAccountId: Get<PalletId>;
 
 		type MaxStringLength: Get<u32>;
 
+		/// The maximum number of members in a threshold maintainer set
+		#[pallet::constant]
+		type MaxMaintainers: Get<u32>;
+
+		/// The maximum number of proposals in a batch, which also bounds inclusion proofs
+		#[pallet::constant]
+		type MaxProposalsPerBatch: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -179,12 +282,61 @@ pub mod pallet {
 	#[pallet::getter(fn resources)]
 	pub type Resources<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_256, ResourceId, ()>;
 
-	/// The proposal nonce used to prevent replay attacks on execute_proposal
+	/// The proposal nonce used to prevent replay attacks on set_resource_with_signature
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_nonce)]
 	pub type ProposalNonce<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::ProposalNonce, ValueQuery>;
 
+	/// The signature scheme of the `Maintainer` key
+	#[pallet::storage]
+	#[pallet::getter(fn maintainer_key_type)]
+	pub type MaintainerKeyType<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, KeyType, ValueQuery>;
+
+	/// The threshold maintainer set, which takes precedence over `Maintainer` when present
+	#[pallet::storage]
+	#[pallet::getter(fn maintainer_set)]
+	pub type Maintainers<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, MaintainerSetOf<T, I>, OptionQuery>;
+
+	/// The nonce of the last executed proposal of every resource, used to prevent replay attacks
+	/// on execute_proposal
+	#[pallet::storage]
+	#[pallet::getter(fn resource_nonce)]
+	pub type ResourceNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, ResourceId, T::ProposalNonce, ValueQuery>;
+
+	/// Resources whose proposals are rejected until they are unpaused
+	#[pallet::storage]
+	#[pallet::getter(fn paused_resources)]
+	pub type PausedResources<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, ResourceId, (), OptionQuery>;
+
+	/// Sensitive proposals waiting for their timelock to expire, by proposal hash
+	#[pallet::storage]
+	#[pallet::getter(fn queued_proposals)]
+	pub type QueuedProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, [u8; 32], QueuedProposalOf<T, I>>;
+
+	/// The block at which each executed proposal was executed, by proposal hash
+	///
+	/// Only the last [Config::ExecutedProposalHistorySize] executed proposals are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn executed_proposals)]
+	pub type ExecutedProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_256, [u8; 32], T::BlockNumber>;
+
+	/// Ring buffer of the hashes of the last executed proposals
+	#[pallet::storage]
+	pub type ExecutedProposalHistory<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, u32, [u8; 32]>;
+
+	/// The slot of the executed proposal history written next
+	#[pallet::storage]
+	pub type NextExecutedProposalIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::storage]
 	pub type MaintainerNonce<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::MaintainerNonce, ValueQuery>;
@@ -198,6 +350,8 @@ pub mod pallet {
 			old_maintainer: BoundedVec<u8, T::MaxStringLength>,
 			new_maintainer: BoundedVec<u8, T::MaxStringLength>,
 		},
+		/// Threshold maintainer set is set
+		MaintainersSet { maintainers: MaintainerSetOf<T, I> },
 		/// Chain now available for transfers (chain_id)
 		ChainWhitelisted { chain_id: T::ChainId },
 		/// Proposal has been approved
@@ -206,6 +360,25 @@ pub mod pallet {
 		ProposalSucceeded { chain_id: T::ChainId, proposal_nonce: T::ProposalNonce },
 		/// Execution of call failed
 		ProposalFailed { chain_id: T::ChainId, proposal_nonce: T::ProposalNonce },
+		/// A proposal of a batch failed and was reverted
+		BatchedProposalFailed { chain_id: T::ChainId, index: u32, error: DispatchError },
+		/// A batch of proposals was executed, of which `failed` proposals failed
+		ProposalBatchExecuted { chain_id: T::ChainId, root: [u8; 32], failed: u32 },
+		/// Sensitive proposal has been queued until its timelock expires
+		ProposalQueued {
+			chain_id: T::ChainId,
+			proposal_nonce: T::ProposalNonce,
+			proposal_hash: [u8; 32],
+			execute_after: T::BlockNumber,
+		},
+		/// Queued proposal has been cancelled
+		QueuedProposalCancelled { proposal_hash: [u8; 32] },
+		/// Chain no longer whitelisted
+		ChainRemoved { chain_id: T::ChainId },
+		/// Proposals for the resource are rejected until it is unpaused
+		ResourcePaused { resource_id: ResourceId },
+		/// Proposals for the resource are accepted again
+		ResourceUnpaused { resource_id: ResourceId },
 	}
 
 	// Errors inform users that something went wrong.
@@ -243,6 +416,28 @@ pub mod pallet {
 		InvalidCall,
 		/// The max limit for string is exceeded
 		StringLimitExceeded,
+		/// Maintainer set is malformed or its threshold cannot be reached
+		InvalidMaintainerSet,
+		/// Maintainer key does not match its key type
+		InvalidMaintainerKey,
+		/// Proposal batch is empty
+		EmptyProposalBatch,
+		/// Inclusion proof does not lead to the root of the batch
+		InvalidInclusionProof,
+		/// No proposal with this hash is queued
+		ProposalNotQueued,
+		/// Queued proposal cannot be executed before its timelock expires
+		ProposalStillTimelocked,
+		/// Proposals for this resource are paused
+		ResourcePaused,
+		/// Resource is not paused
+		ResourceNotPaused,
+		/// Bridge status update could not be decoded
+		InvalidStatusUpdate,
+		/// Maintainer message is not tagged for this call, or has the wrong length
+		InvalidMaintainerMessage,
+		/// Weight of the queued proposal's call exceeds the given weight limit
+		ProposalWeightTooHigh,
 	}
 
 	#[pallet::hooks]
@@ -251,40 +446,42 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Sets the maintainer.
+		///
+		/// The message contains [SET_MAINTAINER_TAG], the nonce as the next 4 bytes and the new
+		/// maintainer key as the remaining bytes, and must be signed by the active maintainer(s).
+		///
+		/// Messages used to start directly with the nonce. They are now tagged like the other
+		/// maintainer handoffs, so that a message signed for another call cannot be replayed here,
+		/// and untagged messages are rejected.
 		#[pallet::weight(T::WeightInfo::set_maintainer())]
 		#[pallet::call_index(0)]
 		pub fn set_maintainer(
 			origin: OriginFor<T>,
-			// message contains the nonce as the first 4 bytes and the last bytes of the message
-			// is the new_maintainer
 			message: BoundedVec<u8, T::MaxStringLength>,
 			signature: BoundedVec<u8, T::MaxStringLength>,
 		) -> DispatchResultWithPostInfo {
 			let _origin = ensure_signed(origin)?;
 			let old_maintainer = <Maintainer<T, I>>::get();
-			let maintainer_nonce = MaintainerNonce::<T, I>::get();
-			let nonce = maintainer_nonce + 1u32.into();
-			// nonce should be the first 4 bytes of this message
-			let mut nonce_bytes = [0u8; 4];
-			nonce_bytes[0..4].copy_from_slice(&message[..4]);
-			let nonce_from_maintainer: T::MaintainerNonce = u32::from_be_bytes(nonce_bytes).into();
-			// Nonce should increment by 1
-			ensure!(nonce_from_maintainer == nonce, Error::<T, I>::InvalidNonce);
+			let (nonce, new_maintainer) =
+				Self::check_maintainer_message(SET_MAINTAINER_TAG, &message)?;
+			ensure!(!new_maintainer.is_empty(), Error::<T, I>::InvalidMaintainerMessage);
+			let new_maintainer = new_maintainer.to_vec();
 
 			// ensure parameter setter is the maintainer
 			ensure!(
-				T::SignatureVerifier::verify(&Self::maintainer(), &message, &signature)
-					.unwrap_or(false),
+				Self::verify_maintainer_signature(&message, &signature),
 				Error::<T, I>::InvalidPermissions
 			);
 			// set the new maintainer nonce
 			MaintainerNonce::<T, I>::put(nonce);
+			// a single maintainer replaces any threshold set
+			Maintainers::<T, I>::kill();
+			// untyped maintainer keys are ECDSA keys
+			MaintainerKeyType::<T, I>::kill();
 			// set the new maintainer
 			Maintainer::<T, I>::try_mutate(|maintainer| {
-				*maintainer = message[4..]
-					.to_vec()
-					.try_into()
-					.map_err(|_| Error::<T, I>::StringLimitExceeded)?;
+				*maintainer =
+					new_maintainer.try_into().map_err(|_| Error::<T, I>::StringLimitExceeded)?;
 				Self::deposit_event(Event::MaintainerSet {
 					old_maintainer,
 					new_maintainer: message,
@@ -304,6 +501,10 @@ pub mod pallet {
 			Self::ensure_admin(origin)?;
 			// set the new maintainer nonce
 			MaintainerNonce::<T, I>::put(nonce);
+			// a single maintainer replaces any threshold set
+			Maintainers::<T, I>::kill();
+			// untyped maintainer keys are ECDSA keys
+			MaintainerKeyType::<T, I>::kill();
 			// set the new maintainer
 			Maintainer::<T, I>::try_mutate(|maintainer| {
 				let old_maintainer = maintainer.clone();
@@ -402,8 +603,7 @@ pub mod pallet {
 			ProposalNonce::<T, I>::set(nonce);
 			// Verify proposal signature
 			ensure!(
-				T::SignatureVerifier::verify(&Self::maintainer(), &proposal_data[..], &signature)
-					.unwrap_or(false),
+				Self::verify_maintainer_signature(&proposal_data[..], &signature),
 				Error::<T, I>::InvalidPermissions,
 			);
 			// ChainId should be whitelisted
@@ -428,20 +628,30 @@ pub mod pallet {
 			// add resource
 			Self::register_resource(r_id)?;
 
-			Self::finalize_execution(src_id, nonce, proposal_call.into())
+			Self::finalize_execution(
+				src_id,
+				nonce,
+				keccak_256(&proposal_data),
+				proposal_call.into(),
+			)?;
+			// relaying a valid proposal is free, while failures are charged to deter spam
+			Ok(Pays::No.into())
 		}
 
 		/// @param origin
 		/// @param src_id
-		/// @param proposal_data: (r_id, nonce, 4 bytes of zeroes, call)
+		/// @param proposal_data: (r_id, function signature, nonce, call)
 		/// @param signature: a signature over the proposal_data
 		///
+		/// The call is either the body of a typed `webb_proposals` proposal, mapped to a call by
+		/// [Config::ProposalDecoder], or a SCALE encoded call.
+		///
 		/// We check:
 		/// 1. That the signature is actually over the proposal data
 		/// 2. That the r_id parsed from the proposal data exists
-		/// 3. That the call from the proposal data and the call input parameter to the function are
-		/// consistent with each other 4. That the execution chain id type parsed from the r_id is
-		/// indeed this chain's id type
+		/// 3. That the nonce is greater than the last executed nonce of the r_id, by at most 1,048
+		/// 4. That the call from the proposal data targets the handler and tree of the r_id
+		/// 5. That the execution chain id type parsed from the r_id is indeed this chain's id type
 		///
 		/// If all these checks pass then we call finalize_execution which actually executes the
 		/// dispatchable call. The dispatchable call is usually a handler function, for instance in
@@ -449,11 +659,15 @@ pub mod pallet {
 		///
 		/// There are a few TODOs left in the function.
 		///
-		/// In the execute_proposal
 		/// # <weight>
-		/// - weight of proposed call, regardless of whether execution is performed
+		/// - the declared weight of the proposed call is included, and refunded when the call is
+		///   queued or uses less
 		/// # </weight>
-		#[pallet::weight((T::WeightInfo::execute_proposal() , Pays::Yes))]
+		#[pallet::weight((
+			T::WeightInfo::execute_proposal()
+				.saturating_add(Pallet::<T, I>::proposal_call_weight(proposal_data)),
+			Pays::Yes
+		))]
 		#[pallet::call_index(6)]
 		pub fn execute_proposal(
 			origin: OriginFor<T>,
@@ -462,34 +676,312 @@ pub mod pallet {
 			signature: BoundedVec<u8, T::MaxStringLength>,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
-			let r_id = Self::parse_r_id_from_proposal_data(&proposal_data)?;
-			let nonce = Self::parse_nonce_from_proposal_data(&proposal_data)?;
-			let parsed_call = Self::parse_call_from_proposal_data(&proposal_data);
-			// Decode executable call
-			let proposal_call = codec::Decode::decode(&mut parsed_call.as_slice())
-				.map_err(|_| Error::<T, I>::InvalidCall)?;
 			// Verify signature of proposal data
 			ensure!(
-				T::SignatureVerifier::verify(&Self::maintainer(), &proposal_data[..], &signature)
-					.unwrap_or(false),
+				Self::verify_maintainer_signature(&proposal_data[..], &signature),
 				Error::<T, I>::InvalidPermissions,
 			);
-			// Ensure decoded call exists in Call filter.
-			ensure!(T::ExecuteProposalFilter::contains(&proposal_call), Error::<T, I>::InvalidCall);
-			ensure!(Self::chain_whitelisted(src_id), Error::<T, I>::ChainNotWhitelisted);
-			ensure!(Self::resource_exists(r_id), Error::<T, I>::ResourceDoesNotExist);
+			let post_info = Self::do_execute_proposal(src_id, &proposal_data)?;
+			// relaying a valid proposal is free, while failures are charged to deter spam
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::execute_proposal()
+						.saturating_add(post_info.actual_weight.unwrap_or_default()),
+				),
+				pays_fee: Pays::No,
+			})
+		}
 
-			// Ensure this chain id matches the r_id
-			let execution_chain_id_type = Self::parse_chain_id_type_from_r_id(r_id);
-			let this_chain_id_type =
-				compute_chain_id_type(T::ChainIdentifier::get(), T::ChainType::get());
+		/// Hands off to a new threshold maintainer set.
+		///
+		/// The message contains [SET_MAINTAINERS_TAG], the nonce as the next 4 bytes and the SCALE
+		/// encoded maintainer set, and must be signed by the active maintainer(s).
+		#[pallet::weight(T::WeightInfo::set_maintainers())]
+		#[pallet::call_index(7)]
+		pub fn set_maintainers(
+			origin: OriginFor<T>,
+			message: BoundedVec<u8, T::MaxStringLength>,
+			signature: BoundedVec<u8, T::MaxStringLength>,
+		) -> DispatchResultWithPostInfo {
+			let _origin = ensure_signed(origin)?;
+			let (nonce, mut body) = Self::check_maintainer_message(SET_MAINTAINERS_TAG, &message)?;
 
+			// ensure the handoff is signed by the active maintainer(s)
 			ensure!(
-				this_chain_id_type == execution_chain_id_type,
-				Error::<T, I>::IncorrectExecutionChainIdType
+				Self::verify_maintainer_signature(&message, &signature),
+				Error::<T, I>::InvalidPermissions
+			);
+			let maintainers = MaintainerSetOf::<T, I>::decode_all(&mut body)
+				.map_err(|_| Error::<T, I>::InvalidMaintainerSet)?;
+			Self::update_maintainers(nonce, maintainers)
+		}
+
+		/// Forcefully set a threshold maintainer set.
+		#[pallet::weight(T::WeightInfo::force_set_maintainers())]
+		#[pallet::call_index(8)]
+		pub fn force_set_maintainers(
+			origin: OriginFor<T>,
+			nonce: T::MaintainerNonce,
+			maintainers: MaintainerSetOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			Self::update_maintainers(nonce, maintainers)
+		}
+
+		/// Hands off to a maintainer key of a given key type.
+		///
+		/// The message contains [SET_TYPED_MAINTAINER_TAG], the nonce as the next 4 bytes, the
+		/// SCALE encoded [KeyType] and the new maintainer key, and must be signed by the active
+		/// maintainer(s).
+		#[pallet::weight(T::WeightInfo::set_typed_maintainer())]
+		#[pallet::call_index(9)]
+		pub fn set_typed_maintainer(
+			origin: OriginFor<T>,
+			message: BoundedVec<u8, T::MaxStringLength>,
+			signature: BoundedVec<u8, T::MaxStringLength>,
+		) -> DispatchResultWithPostInfo {
+			let _origin = ensure_signed(origin)?;
+			let (nonce, mut rest) =
+				Self::check_maintainer_message(SET_TYPED_MAINTAINER_TAG, &message)?;
+
+			// ensure the handoff is signed by the active maintainer(s)
+			ensure!(
+				Self::verify_maintainer_signature(&message, &signature),
+				Error::<T, I>::InvalidPermissions
+			);
+			let key_type =
+				KeyType::decode(&mut rest).map_err(|_| Error::<T, I>::InvalidMaintainerKey)?;
+			let new_maintainer =
+				rest.to_vec().try_into().map_err(|_| Error::<T, I>::StringLimitExceeded)?;
+			Self::update_typed_maintainer(nonce, key_type, new_maintainer)
+		}
+
+		/// @param origin
+		/// @param src_id
+		/// @param proposals: the proposal data of every proposal of the batch, each with its
+		/// inclusion proof
+		/// @param root: the Merkle root of the batch
+		/// @param signature: a signature over [PROPOSAL_BATCH_TAG] followed by the root
+		///
+		/// The leaves of the batch are `keccak256(keccak256(proposal_data))` and every parent node
+		/// is the keccak256 hash of its two children in ascending order, so that inclusion proofs
+		/// are the list of sibling hashes from the leaf up to the root.
+		///
+		/// Once the signature over the root is verified, every proposal whose inclusion proof
+		/// matches the root is executed with the same checks as in execute_proposal. A proposal
+		/// that fails is reverted and reported through a `BatchedProposalFailed` event without
+		/// affecting the other proposals of the batch.
+		///
+		/// The weight accounts for the declared weight of every call of the batch, and the
+		/// unused part of it is refunded once the batch has been executed.
+		#[pallet::weight((
+			T::WeightInfo::execute_proposal_batch(proposals.len() as u32).saturating_add(
+				proposals.iter().fold(Weight::zero(), |acc, proposal| {
+					acc.saturating_add(Pallet::<T, I>::proposal_call_weight(&proposal.proposal_data))
+				})
+			),
+			Pays::Yes
+		))]
+		#[pallet::call_index(11)]
+		pub fn execute_proposal_batch(
+			origin: OriginFor<T>,
+			src_id: T::ChainId,
+			proposals: BoundedVec<BatchedProposalOf<T, I>, T::MaxProposalsPerBatch>,
+			root: [u8; 32],
+			signature: BoundedVec<u8, T::MaxStringLength>,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			ensure!(!proposals.is_empty(), Error::<T, I>::EmptyProposalBatch);
+			// Verify signature of the batch root, under its domain tag
+			ensure!(
+				Self::verify_maintainer_signature(
+					&[PROPOSAL_BATCH_TAG, &root].concat(),
+					&signature
+				),
+				Error::<T, I>::InvalidPermissions,
+			);
+
+			let batch_size = proposals.len() as u32;
+			let mut calls_weight = Weight::zero();
+			let mut failed = 0u32;
+			for (index, proposal) in proposals.into_iter().enumerate() {
+				let leaf = keccak_256(&keccak_256(&proposal.proposal_data));
+				let result = if Self::compute_merkle_root(leaf, &proposal.proof) == root {
+					with_storage_layer(|| {
+						Self::do_execute_proposal(src_id, &proposal.proposal_data)
+							.map_err(|e| e.error)
+					})
+					.map(|post_info| post_info.actual_weight.unwrap_or_default())
+					.map_err(|error| {
+						// the call may have run before failing, charge its declared weight
+						calls_weight = calls_weight
+							.saturating_add(Self::proposal_call_weight(&proposal.proposal_data));
+						error
+					})
+				} else {
+					Err(Error::<T, I>::InvalidInclusionProof.into())
+				};
+				match result {
+					Ok(weight) => calls_weight = calls_weight.saturating_add(weight),
+					Err(error) => {
+						failed += 1;
+						Self::deposit_event(Event::BatchedProposalFailed {
+							chain_id: src_id,
+							index: index as u32,
+							error,
+						});
+					},
+				}
+			}
+			Self::deposit_event(Event::ProposalBatchExecuted { chain_id: src_id, root, failed });
+			// the batch is free to relay unless some of its proposals failed
+			let pays_fee = if failed == 0 { Pays::No } else { Pays::Yes };
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::execute_proposal_batch(batch_size).saturating_add(calls_weight),
+				),
+				pays_fee,
+			})
+		}
+
+		/// Executes a queued sensitive proposal once its timelock has expired.
+		///
+		/// Anyone can execute a queued proposal, since it has already been signed by the
+		/// maintainer(s) and checked when it was queued.
+		///
+		/// The queued call is not known when the weight is declared, so the caller bounds it with
+		/// `weight_limit`, which must cover the declared weight of the call, see
+		/// [Pallet::proposal_call_weight]. The unused part of the weight is refunded.
+		#[pallet::weight((
+			T::WeightInfo::execute_queued_proposal().saturating_add(*weight_limit),
+			Pays::Yes
+		))]
+		#[pallet::call_index(12)]
+		pub fn execute_queued_proposal(
+			origin: OriginFor<T>,
+			proposal_hash: [u8; 32],
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let queued = QueuedProposals::<T, I>::get(proposal_hash)
+				.ok_or(Error::<T, I>::ProposalNotQueued)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= queued.execute_after,
+				Error::<T, I>::ProposalStillTimelocked
+			);
+			ensure!(
+				Self::proposal_call_weight(&queued.proposal_data).all_lte(weight_limit),
+				Error::<T, I>::ProposalWeightTooHigh
+			);
+			QueuedProposals::<T, I>::remove(proposal_hash);
+
+			// The chain or resource may have been isolated during the timelock
+			let r_id = Self::parse_r_id_from_proposal_data(&queued.proposal_data)?;
+			ensure!(Self::chain_whitelisted(queued.src_id), Error::<T, I>::ChainNotWhitelisted);
+			ensure!(!Self::resource_paused(r_id), Error::<T, I>::ResourcePaused);
+			let nonce = Self::parse_nonce_from_proposal_data(&queued.proposal_data)?;
+			let proposal_call = Self::decode_proposal_call(&queued.proposal_data)?;
+			let post_info = Self::finalize_execution(
+				queued.src_id,
+				nonce,
+				proposal_hash,
+				proposal_call.into(),
+			)?;
+			Ok(PostDispatchInfo {
+				actual_weight: Some(
+					T::WeightInfo::execute_queued_proposal()
+						.saturating_add(post_info.actual_weight.unwrap_or_default()),
+				),
+				pays_fee: Pays::No,
+			})
+		}
+
+		/// Cancels a queued sensitive proposal during its timelock.
+		#[pallet::weight(T::WeightInfo::cancel_queued_proposal())]
+		#[pallet::call_index(13)]
+		pub fn cancel_queued_proposal(
+			origin: OriginFor<T>,
+			proposal_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			ensure!(
+				QueuedProposals::<T, I>::contains_key(proposal_hash),
+				Error::<T, I>::ProposalNotQueued
 			);
+			QueuedProposals::<T, I>::remove(proposal_hash);
+			Self::deposit_event(Event::QueuedProposalCancelled { proposal_hash });
+			Ok(().into())
+		}
 
-			Self::finalize_execution(src_id, nonce, proposal_call.into())
+		/// Stops accepting proposals from a whitelisted chain.
+		#[pallet::weight(T::WeightInfo::remove_chain())]
+		#[pallet::call_index(14)]
+		pub fn remove_chain(origin: OriginFor<T>, id: T::ChainId) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			Self::unwhitelist(id)
+		}
+
+		/// Stops accepting proposals for a resource, without removing it or its nonce.
+		#[pallet::weight(T::WeightInfo::pause_resource())]
+		#[pallet::call_index(15)]
+		pub fn pause_resource(origin: OriginFor<T>, id: ResourceId) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			Self::pause(id)
+		}
+
+		/// Resumes accepting proposals for a paused resource.
+		#[pallet::weight(T::WeightInfo::unpause_resource())]
+		#[pallet::call_index(16)]
+		pub fn unpause_resource(
+			origin: OriginFor<T>,
+			id: ResourceId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			Self::unpause(id)
+		}
+
+		/// Removes a chain, or pauses or unpauses a resource.
+		///
+		/// The message contains [UPDATE_BRIDGE_STATUS_TAG], the maintainer nonce as the next 4
+		/// bytes and the SCALE encoded [BridgeStatusUpdate], and must be signed by the active
+		/// maintainer(s).
+		#[pallet::weight(T::WeightInfo::update_bridge_status())]
+		#[pallet::call_index(17)]
+		pub fn update_bridge_status(
+			origin: OriginFor<T>,
+			message: BoundedVec<u8, T::MaxStringLength>,
+			signature: BoundedVec<u8, T::MaxStringLength>,
+		) -> DispatchResultWithPostInfo {
+			let _origin = ensure_signed(origin)?;
+			let (nonce, mut body) =
+				Self::check_maintainer_message(UPDATE_BRIDGE_STATUS_TAG, &message)?;
+
+			// ensure the update is signed by the active maintainer(s)
+			ensure!(
+				Self::verify_maintainer_signature(&message, &signature),
+				Error::<T, I>::InvalidPermissions
+			);
+			let update = BridgeStatusUpdate::<T::ChainId>::decode_all(&mut body)
+				.map_err(|_| Error::<T, I>::InvalidStatusUpdate)?;
+			MaintainerNonce::<T, I>::put(nonce);
+			match update {
+				BridgeStatusUpdate::RemoveChain(id) => Self::unwhitelist(id),
+				BridgeStatusUpdate::PauseResource(id) => Self::pause(id),
+				BridgeStatusUpdate::UnpauseResource(id) => Self::unpause(id),
+			}
+		}
+
+		/// Forcefully set a maintainer key of a given key type.
+		#[pallet::weight(T::WeightInfo::force_set_typed_maintainer())]
+		#[pallet::call_index(10)]
+		pub fn force_set_typed_maintainer(
+			origin: OriginFor<T>,
+			nonce: T::MaintainerNonce,
+			key_type: KeyType,
+			new_maintainer: BoundedVec<u8, T::MaxStringLength>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin(origin)?;
+			Self::update_typed_maintainer(nonce, key_type, new_maintainer)
 		}
 	}
 }
@@ -513,11 +1005,113 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::resources(id).is_some()
 	}
 
+	/// Checks a signature over `message` against the active maintainer(s).
+	///
+	/// Without a threshold set the signature must be from the single `Maintainer` key.
+	/// Otherwise it must either be a Schnorr signature of the set's aggregate key, or
+	/// concatenated ECDSA signatures from at least `threshold` distinct members.
+	pub fn verify_maintainer_signature(message: &[u8], signature: &[u8]) -> bool {
+		let set = match Self::maintainer_set() {
+			Some(set) => set,
+			None => {
+				let key = Self::maintainer();
+				return match Self::maintainer_key_type() {
+					KeyType::Ecdsa => T::SignatureVerifier::verify(&key, message, signature).ok(),
+					KeyType::Sr25519 => Sr25519Verifier::verify(&key, message, signature).ok(),
+					KeyType::Ed25519 => Ed25519Verifier::verify(&key, message, signature).ok(),
+					KeyType::Schnorr => SchnorrVerifier::verify(&key, message, signature).ok(),
+				}
+				.unwrap_or(false)
+			},
+		};
+
+		if signature.len() == schnorr::SIGNATURE_LENGTH {
+			return set
+				.aggregate_key
+				.map_or(false, |key| schnorr::verify_schnorr_signature(&key, message, signature))
+		}
+
+		if signature.is_empty() || signature.len() % ecdsa::SIGNATURE_LENGTH != 0 {
+			return false
+		}
+		let mut signers: Vec<Vec<u8>> = Vec::new();
+		for sig in signature.chunks(ecdsa::SIGNATURE_LENGTH) {
+			let signer = match T::SignatureVerifier::recover_pub_key(message, sig) {
+				Ok(signer) => signer,
+				Err(_) => return false,
+			};
+			// every signature must come from a distinct member
+			if signers.contains(&signer) || !set.members.iter().any(|m| m[..] == signer[..]) {
+				return false
+			}
+			signers.push(signer);
+		}
+		signers.len() >= set.threshold as usize
+	}
+
+	/// Returns the keys of the active maintainer(s)
+	pub fn maintainer_keys() -> Vec<Vec<u8>> {
+		match Self::maintainer_set() {
+			Some(set) => set.members.into_iter().map(|m| m.into_inner()).collect(),
+			None => Some(Self::maintainer().into_inner())
+				.filter(|key| !key.is_empty())
+				.into_iter()
+				.collect(),
+		}
+	}
+
+	/// Returns the number of maintainer signatures a proposal needs
+	pub fn maintainer_threshold() -> u32 {
+		Self::maintainer_set().map_or(1, |set| set.threshold)
+	}
+
+	/// Returns the aggregate Schnorr key of the active maintainer set, if any
+	pub fn maintainer_aggregate_key() -> Option<Vec<u8>> {
+		Self::maintainer_set()
+			.and_then(|set| set.aggregate_key)
+			.map(|key| key.into_inner())
+	}
+
+	/// Returns every whitelisted chain with its transaction count
+	pub fn whitelisted_chains() -> Vec<(T::ChainId, T::ProposalNonce)> {
+		ChainNonces::<T, I>::iter().collect()
+	}
+
+	/// Returns every registered resource with the nonce of its last executed proposal
+	pub fn registered_resources() -> Vec<(ResourceId, T::ProposalNonce)> {
+		Resources::<T, I>::iter_keys()
+			.map(|r_id| (r_id, Self::resource_nonce(r_id)))
+			.collect()
+	}
+
+	/// Returns the block at which the proposal with this hash was executed, if it is one of the
+	/// last [Config::ExecutedProposalHistorySize] executed proposals
+	pub fn proposal_executed_at(proposal_hash: [u8; 32]) -> Option<T::BlockNumber> {
+		Self::executed_proposals(proposal_hash)
+	}
+
 	/// Checks if a chain exists as a whitelisted destination
 	pub fn chain_whitelisted(id: T::ChainId) -> bool {
 		Self::chains(id).is_some()
 	}
 
+	/// Checks that a maintainer message starts with `tag` followed by the next maintainer nonce,
+	/// returning the nonce and the rest of the message
+	pub fn check_maintainer_message<'a>(
+		tag: &[u8],
+		message: &'a [u8],
+	) -> Result<(T::MaintainerNonce, &'a [u8]), DispatchError> {
+		let rest = message.strip_prefix(tag).ok_or(Error::<T, I>::InvalidMaintainerMessage)?;
+		ensure!(rest.len() >= 4, Error::<T, I>::InvalidNonce);
+		let (nonce_bytes, body) = rest.split_at(4);
+		let nonce_from_maintainer: T::MaintainerNonce =
+			u32::from_be_bytes(nonce_bytes.try_into().unwrap_or_default()).into();
+		let nonce = MaintainerNonce::<T, I>::get() + 1u32.into();
+		// Nonce should increment by 1
+		ensure!(nonce_from_maintainer == nonce, Error::<T, I>::InvalidNonce);
+		Ok((nonce, body))
+	}
+
 	pub fn parse_r_id_from_proposal_data(
 		proposal_data: &[u8],
 	) -> Result<ResourceId, DispatchError> {
@@ -539,6 +1133,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		proposal_data[40..].to_vec()
 	}
 
+	/// Decodes the call of a proposal.
+	///
+	/// Typed proposals are mapped to their call by [Config::ProposalDecoder], while the body of
+	/// any other proposal is a SCALE encoded call.
+	pub fn decode_proposal_call(proposal_data: &[u8]) -> Result<T::Proposal, DispatchError> {
+		let header_bytes: [u8; ProposalHeader::LENGTH] = proposal_data
+			.get(..ProposalHeader::LENGTH)
+			.and_then(|bytes| bytes.try_into().ok())
+			.ok_or(Error::<T, I>::InvalidProposalData)?;
+		let header = ProposalHeader::from(header_bytes);
+		let mut body = &proposal_data[ProposalHeader::LENGTH..];
+		match T::ProposalDecoder::decode_proposal(&header, proposal_data) {
+			Some(call) => Ok(call),
+			None => codec::Decode::decode(&mut body).map_err(|_| Error::<T, I>::InvalidCall.into()),
+		}
+	}
+
+	/// Declared weight of the call of a proposal, zero if the call cannot be decoded.
+	pub fn proposal_call_weight(proposal_data: &[u8]) -> Weight {
+		Self::decode_proposal_call(proposal_data)
+			.map(|call| call.get_dispatch_info().weight)
+			.unwrap_or_default()
+	}
+
 	pub fn parse_chain_id_type_from_r_id(r_id: ResourceId) -> u64 {
 		let mut chain_id_type = [0u8; 8];
 		let raw = r_id.0;
@@ -554,6 +1172,43 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	// *** Admin methods ***
 
+	/// Replaces the active maintainer(s) with a single key of the given type
+	fn update_typed_maintainer(
+		nonce: T::MaintainerNonce,
+		key_type: KeyType,
+		new_maintainer: BoundedVec<u8, T::MaxStringLength>,
+	) -> DispatchResultWithPostInfo {
+		ensure!(
+			new_maintainer.len() == key_type.public_key_length(),
+			Error::<T, I>::InvalidMaintainerKey
+		);
+		// set the new maintainer nonce
+		MaintainerNonce::<T, I>::put(nonce);
+		// a single maintainer replaces any threshold set
+		Maintainers::<T, I>::kill();
+		MaintainerKeyType::<T, I>::put(key_type);
+		let old_maintainer = Maintainer::<T, I>::get();
+		Maintainer::<T, I>::put(new_maintainer.clone());
+		Self::deposit_event(Event::MaintainerSet { old_maintainer, new_maintainer });
+		Ok(().into())
+	}
+
+	/// Replaces the active maintainer(s) with a threshold maintainer set
+	fn update_maintainers(
+		nonce: T::MaintainerNonce,
+		maintainers: MaintainerSetOf<T, I>,
+	) -> DispatchResultWithPostInfo {
+		ensure!(maintainers.is_valid(), Error::<T, I>::InvalidMaintainerSet);
+		// set the new maintainer nonce
+		MaintainerNonce::<T, I>::put(nonce);
+		// the threshold set replaces the single maintainer
+		Maintainer::<T, I>::kill();
+		MaintainerKeyType::<T, I>::kill();
+		Maintainers::<T, I>::put(maintainers.clone());
+		Self::deposit_event(Event::MaintainersSet { maintainers });
+		Ok(().into())
+	}
+
 	/// Register a method for a resource Id, enabling associated transfers
 	pub fn register_resource(id: ResourceId) -> DispatchResultWithPostInfo {
 		Resources::<T, I>::insert(id, ());
@@ -563,6 +1218,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Removes a resource ID, disabling associated transfer
 	pub fn unregister_resource(id: ResourceId) -> DispatchResultWithPostInfo {
 		Resources::<T, I>::remove(id);
+		PausedResources::<T, I>::remove(id);
+		Ok(().into())
+	}
+
+	/// Asserts if a resource is paused
+	pub fn resource_paused(id: ResourceId) -> bool {
+		PausedResources::<T, I>::contains_key(id)
+	}
+
+	/// Pause a resource, rejecting its proposals
+	pub fn pause(id: ResourceId) -> DispatchResultWithPostInfo {
+		ensure!(Self::resource_exists(id), Error::<T, I>::ResourceDoesNotExist);
+		ensure!(!Self::resource_paused(id), Error::<T, I>::ResourcePaused);
+		PausedResources::<T, I>::insert(id, ());
+		Self::deposit_event(Event::ResourcePaused { resource_id: id });
+		Ok(().into())
+	}
+
+	/// Unpause a resource, accepting its proposals again
+	pub fn unpause(id: ResourceId) -> DispatchResultWithPostInfo {
+		ensure!(Self::resource_paused(id), Error::<T, I>::ResourceNotPaused);
+		PausedResources::<T, I>::remove(id);
+		Self::deposit_event(Event::ResourceUnpaused { resource_id: id });
+		Ok(().into())
+	}
+
+	/// Remove a whitelisted chain ID
+	pub fn unwhitelist(id: T::ChainId) -> DispatchResultWithPostInfo {
+		ensure!(Self::chain_whitelisted(id), Error::<T, I>::ChainNotWhitelisted);
+		ChainNonces::<T, I>::remove(id);
+		Self::deposit_event(Event::ChainRemoved { chain_id: id });
 		Ok(().into())
 	}
 
@@ -577,21 +1263,144 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	// *** Proposal voting and execution methods ***
 
+	/// Computes the root of a batch from the leaf of a proposal and its inclusion proof
+	pub fn compute_merkle_root(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+		proof.iter().fold(leaf, |node, sibling| {
+			let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+			keccak_256(&[left, right].concat())
+		})
+	}
+
+	/// Builds the Merkle root of a batch of proposals and the inclusion proof of every proposal
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
+	pub fn build_proposal_batch(proposals: &[Vec<u8>]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+		let mut level: Vec<[u8; 32]> =
+			proposals.iter().map(|proposal| keccak_256(&keccak_256(proposal))).collect();
+		let mut positions: Vec<usize> = (0..proposals.len()).collect();
+		let mut proofs = sp_std::vec![Vec::new(); proposals.len()];
+		while level.len() > 1 {
+			for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+				// an odd node out has no sibling and moves up as is
+				if let Some(sibling) = level.get(*position ^ 1) {
+					proof.push(*sibling);
+				}
+				*position /= 2;
+			}
+			level = level
+				.chunks(2)
+				.map(|pair| match pair {
+					[left, right] => Self::compute_merkle_root(*left, &[*right]),
+					_ => pair[0],
+				})
+				.collect();
+		}
+		(level[0], proofs)
+	}
+
+	/// Checks and executes a proposal whose signature has been verified
+	fn do_execute_proposal(src_id: T::ChainId, proposal_data: &[u8]) -> DispatchResultWithPostInfo {
+		let r_id = Self::parse_r_id_from_proposal_data(proposal_data)?;
+		let nonce = Self::parse_nonce_from_proposal_data(proposal_data)?;
+		// Decode executable call
+		let proposal_call = Self::decode_proposal_call(proposal_data)?;
+		// Ensure decoded call exists in Call filter.
+		ensure!(T::ExecuteProposalFilter::contains(&proposal_call), Error::<T, I>::InvalidCall);
+		ensure!(Self::chain_whitelisted(src_id), Error::<T, I>::ChainNotWhitelisted);
+		ensure!(Self::resource_exists(r_id), Error::<T, I>::ResourceDoesNotExist);
+		ensure!(!Self::resource_paused(r_id), Error::<T, I>::ResourcePaused);
+		// Nonce should be greater than the last executed nonce of this resource
+		let resource_nonce = ResourceNonces::<T, I>::get(r_id);
+		ensure!(resource_nonce < nonce, Error::<T, I>::InvalidNonce);
+		// Nonce should increment by a maximum of 1,048
+		ensure!(
+			nonce <= resource_nonce + T::ProposalNonce::from(1_048u32),
+			Error::<T, I>::InvalidNonce
+		);
+		// Ensure the decoded call acts on this resource
+		ensure!(
+			T::ProposalResourceMatcher::contains(&r_id, &proposal_call),
+			Error::<T, I>::CallDoesNotMatchResourceId
+		);
+
+		// Ensure this chain id matches the r_id
+		let execution_chain_id_type = Self::parse_chain_id_type_from_r_id(r_id);
+		let this_chain_id_type =
+			compute_chain_id_type(T::ChainIdentifier::get(), T::ChainType::get());
+
+		ensure!(
+			this_chain_id_type == execution_chain_id_type,
+			Error::<T, I>::IncorrectExecutionChainIdType
+		);
+		ResourceNonces::<T, I>::insert(r_id, nonce);
+
+		let delay = T::ProposalDelay::get();
+		if !delay.is_zero() && T::SensitiveProposalFilter::contains(&proposal_call) {
+			return Self::queue_proposal(src_id, nonce, proposal_data, delay)
+		}
+
+		Self::finalize_execution(src_id, nonce, keccak_256(proposal_data), proposal_call.into())
+	}
+
+	/// Timelocks a proposal for `delay` blocks
+	fn queue_proposal(
+		src_id: T::ChainId,
+		nonce: T::ProposalNonce,
+		proposal_data: &[u8],
+		delay: T::BlockNumber,
+	) -> DispatchResultWithPostInfo {
+		let proposal_hash = keccak_256(proposal_data);
+		let execute_after = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+		let queued = QueuedProposal {
+			src_id,
+			proposal_data: proposal_data
+				.to_vec()
+				.try_into()
+				.map_err(|_| Error::<T, I>::StringLimitExceeded)?,
+			execute_after,
+		};
+		QueuedProposals::<T, I>::insert(proposal_hash, queued);
+		Self::deposit_event(Event::ProposalQueued {
+			chain_id: src_id,
+			proposal_nonce: nonce,
+			proposal_hash,
+			execute_after,
+		});
+		Ok(().into())
+	}
+
 	#[allow(clippy::boxed_local)]
-	/// Execute the proposal and signals the result as an event
+	/// Execute the proposal, record its hash and signals the result as an event
+	///
+	/// The actual weight of the call is returned in the post dispatch info.
 	fn finalize_execution(
 		src_id: T::ChainId,
 		nonce: T::ProposalNonce,
+		proposal_hash: [u8; 32],
 		call: Box<T::Proposal>,
 	) -> DispatchResultWithPostInfo {
 		Self::deposit_event(Event::ProposalApproved { chain_id: src_id, proposal_nonce: nonce });
-		call.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into())
-			.map(|_| ())
+		let info = call.get_dispatch_info();
+		let post_info = call
+			.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into())
 			.map_err(|e| e.error)?;
+		Self::record_executed_proposal(proposal_hash);
 		Self::deposit_event(Event::ProposalSucceeded { chain_id: src_id, proposal_nonce: nonce });
-		// Increment the nonce once the proposal succeeds
-		ProposalNonce::<T, I>::put(nonce);
-		Ok(().into())
+		Ok(Some(post_info.calc_actual_weight(&info)).into())
+	}
+
+	/// Records an executed proposal, pruning the oldest one once the history is full
+	fn record_executed_proposal(proposal_hash: [u8; 32]) {
+		let history_size = T::ExecutedProposalHistorySize::get();
+		if history_size == 0 {
+			return
+		}
+		let index = NextExecutedProposalIndex::<T, I>::get();
+		if let Some(pruned_hash) = ExecutedProposalHistory::<T, I>::get(index) {
+			ExecutedProposals::<T, I>::remove(pruned_hash);
+		}
+		ExecutedProposalHistory::<T, I>::insert(index, proposal_hash);
+		ExecutedProposals::<T, I>::insert(proposal_hash, <frame_system::Pallet<T>>::block_number());
+		NextExecutedProposalIndex::<T, I>::put(index.saturating_add(1) % history_size);
 	}
 }
 
@@ -608,6 +1417,10 @@ impl<T: Config<I>, I: 'static> SetMaintainer<T::MaintainerNonce, T::MaxStringLen
 		ensure!(next_maintainer_nonce == nonce, Error::<T, I>::InvalidNonce);
 		// set the new maintainer nonce
 		MaintainerNonce::<T, I>::put(nonce);
+		// a single maintainer replaces any threshold set
+		Maintainers::<T, I>::kill();
+		// untyped maintainer keys are ECDSA keys
+		MaintainerKeyType::<T, I>::kill();
 		// set the new maintainer
 		Maintainer::<T, I>::try_mutate(|maintainer| {
 			let old_maintainer = maintainer.clone();
@@ -618,6 +1431,12 @@ impl<T: Config<I>, I: 'static> SetMaintainer<T::MaintainerNonce, T::MaxStringLen
 	}
 }
 
+impl<T: Config<I>, I: 'static> VerifyMaintainerSignature for Pallet<T, I> {
+	fn verify_maintainer_signature(message: &[u8], signature: &[u8]) -> bool {
+		Self::verify_maintainer_signature(message, signature)
+	}
+}
+
 /// Simple ensure origin for the bridge account
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo, RuntimeDebug)]
 pub struct EnsureBridge<T, I>(sp_std::marker::PhantomData<(T, I)>);
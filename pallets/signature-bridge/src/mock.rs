@@ -2,16 +2,21 @@
 
 use super::*;
 
-use frame_support::{assert_ok, parameter_types, traits::Contains, PalletId};
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::{Contains, ContainsPair},
+	PalletId,
+};
 use frame_system::{self as system};
 use sp_core::H256;
 use sp_keystore::{testing::KeyStore, KeystoreExt};
 use sp_runtime::{
 	testing::Header,
-	traits::{AccountIdConversion, BlakeTwo256, ConstU32, IdentityLookup},
+	traits::{AccountIdConversion, BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
 };
 use sp_std::convert::{TryFrom, TryInto};
 use std::{sync::Arc, vec};
+use webb_primitives::{signing::Eip712SignatureVerifier, webb_proposals::FunctionSignature};
 
 use crate::{self as pallet_signature_bridge, Config};
 pub use pallet_balances;
@@ -114,6 +119,70 @@ impl Contains<RuntimeCall> for ExecuteAllProposalsFilter {
 	}
 }
 
+parameter_types! {
+	// When set, only proposals for this resource ID match their call
+	pub static MatchingResource: Option<ResourceId> = None;
+}
+
+pub struct MockProposalResourceMatcher;
+impl ContainsPair<ResourceId, RuntimeCall> for MockProposalResourceMatcher {
+	fn contains(r_id: &ResourceId, _c: &RuntimeCall) -> bool {
+		MatchingResource::get().map_or(true, |id| id == *r_id)
+	}
+}
+
+parameter_types! {
+	// When set, every proposal call is treated as sensitive and timelocked
+	pub static QueueAllProposals: bool = false;
+}
+
+pub struct MockSensitiveProposalFilter;
+impl Contains<RuntimeCall> for MockSensitiveProposalFilter {
+	fn contains(_c: &RuntimeCall) -> bool {
+		QueueAllProposals::get()
+	}
+}
+
+// Function signature of a typed proposal whose body is the remark to make
+pub const REMARK_FUNCTION_SIG: FunctionSignature = FunctionSignature([0x72, 0x65, 0x6d, 0x6b]);
+
+pub struct MockProposalDecoder;
+impl TypedProposalDecoder<RuntimeCall> for MockProposalDecoder {
+	fn decode_proposal(header: &ProposalHeader, proposal_data: &[u8]) -> Option<RuntimeCall> {
+		match header.function_signature() {
+			REMARK_FUNCTION_SIG => Some(RuntimeCall::System(system::Call::remark {
+				remark: proposal_data[ProposalHeader::LENGTH..].to_vec(),
+			})),
+			_ => None,
+		}
+	}
+}
+
+parameter_types! {
+	// When set, maintainers sign proposals as EIP-712 typed data
+	pub static SignWithEip712: bool = false;
+	pub static ExecutedProposalHistorySize: u32 = 3;
+	pub ProposalSigningDomain: [u8; 32] = ecdsa::eip712_domain_separator(
+		b"Webb Signature Bridge",
+		b"1",
+		compute_chain_id_type(ChainIdentifier::get(), ChainType::get()),
+		keccak_256(&BridgeAccountId::get().0),
+	);
+}
+
+pub struct MockSignatureVerifier;
+impl SigningSystem for MockSignatureVerifier {
+	type Error = ecdsa::EcdsaVerifyError;
+
+	fn recover_pub_key(msg: &[u8], sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		if SignWithEip712::get() {
+			Eip712SignatureVerifier::<ProposalSigningDomain>::recover_pub_key(msg, sig)
+		} else {
+			webb_primitives::signing::SignatureVerifier::recover_pub_key(msg, sig)
+		}
+	}
+}
+
 impl Config for Test {
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type BridgeAccountId = BridgeAccountId;
@@ -122,13 +191,20 @@ impl Config for Test {
 	type ChainType = ChainType;
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalLifetime = ProposalLifetime;
+	type ExecutedProposalHistorySize = ExecutedProposalHistorySize;
+	type ProposalDelay = ConstU64<10>;
 	type ProposalNonce = u32;
 	type SetResourceProposalFilter = SetResourceProposalFilter;
 	type ExecuteProposalFilter = ExecuteAllProposalsFilter;
+	type ProposalResourceMatcher = MockProposalResourceMatcher;
+	type SensitiveProposalFilter = MockSensitiveProposalFilter;
+	type ProposalDecoder = MockProposalDecoder;
 	type MaintainerNonce = u32;
 	type MaxStringLength = ConstU32<1000>;
+	type MaxMaintainers = ConstU32<10>;
+	type MaxProposalsPerBatch = ConstU32<32>;
 	type Proposal = RuntimeCall;
-	type SignatureVerifier = webb_primitives::signing::SignatureVerifier;
+	type SignatureVerifier = MockSignatureVerifier;
 	type WeightInfo = ();
 }
 
@@ -9,9 +9,14 @@ use super::{
 };
 use crate::{
 	mock::new_test_ext_initialized,
+	types::{
+		BatchedProposal, BridgeStatusUpdate, MaintainerSet, PROPOSAL_BATCH_TAG,
+		SET_MAINTAINERS_TAG, SET_MAINTAINER_TAG, SET_TYPED_MAINTAINER_TAG,
+		UPDATE_BRIDGE_STATUS_TAG,
+	},
 	{self as pallet_bridge},
 };
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{assert_err, assert_noop, assert_ok, dispatch::Pays, BoundedVec};
 use hex_literal::hex;
 use sp_core::{
 	ecdsa::{self, Signature},
@@ -19,8 +24,12 @@ use sp_core::{
 };
 use sp_runtime::traits::ConstU32;
 use webb_primitives::{
+	signing::{
+		ecdsa::{eip191_hash, eip712_domain_separator, eip712_hash},
+		Eip191SignatureVerifier, KeyType,
+	},
 	utils::{compute_chain_id_type, derive_resource_id},
-	webb_proposals::SubstrateTargetSystem,
+	webb_proposals::{FunctionSignature, SubstrateTargetSystem},
 };
 
 #[test]
@@ -217,7 +226,7 @@ fn set_maintainer_should_work() {
 		let bounded_old_maintainer: BoundedVec<u8, ConstU32<1000>> =
 			old_maintainer.try_into().unwrap();
 		Maintainer::<Test, _>::put(bounded_old_maintainer);
-		let mut message = vec![];
+		let mut message = SET_MAINTAINER_TAG.to_vec();
 		let nonce = 1u32.to_be_bytes();
 		message.extend_from_slice(&nonce);
 		message.extend_from_slice(&new_maintainer);
@@ -272,3 +281,1283 @@ fn should_fail_on_invalid_proposal_call() {
 		);
 	})
 }
+
+fn uncompressed_key(pair: &ecdsa::Pair) -> BoundedVec<u8, ConstU32<1000>> {
+	libsecp256k1::PublicKey::parse_compressed(&pair.public().0).unwrap().serialize()[1..]
+		.to_vec()
+		.try_into()
+		.unwrap()
+}
+
+fn make_maintainer_set(
+	pairs: &[ecdsa::Pair],
+	threshold: u32,
+	aggregate_key: Option<Vec<u8>>,
+) -> MaintainerSetOf<Test, ()> {
+	MaintainerSet {
+		members: pairs.iter().map(uncompressed_key).collect::<Vec<_>>().try_into().unwrap(),
+		threshold,
+		aggregate_key: aggregate_key.map(|key| key.try_into().unwrap()),
+	}
+}
+
+fn maintainer_message(tag: &[u8], nonce: u32, body: &[u8]) -> Vec<u8> {
+	let mut message = tag.to_vec();
+	message.extend_from_slice(&nonce.to_be_bytes());
+	message.extend_from_slice(body);
+	message
+}
+
+fn sign_with(pairs: &[&ecdsa::Pair], message: &[u8]) -> Vec<u8> {
+	let msg = keccak_256(message);
+	pairs.iter().flat_map(|pair| pair.sign_prehashed(&msg).0.to_vec()).collect()
+}
+
+#[test]
+fn threshold_maintainers_should_execute_proposal() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pairs: Vec<ecdsa::Pair> = (0..3).map(|_| ecdsa::Pair::generate().0).collect();
+	let outsider = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let maintainers = make_maintainer_set(&pairs, 2, None);
+		assert_ok!(Bridge::force_set_maintainers(RuntimeOrigin::root(), 1u32, maintainers.clone()));
+		assert_eq!(Bridge::maintainer_set(), Some(maintainers.clone()));
+		assert_eq!(Bridge::maintainer_keys().len(), 3);
+		assert_eq!(Bridge::maintainer_threshold(), 2);
+
+		let call = make_proposal(vec![10]);
+		let nonce = [0u8, 0u8, 0u8, 1u8];
+		let prop_data = make_proposal_data(r_id.encode(), nonce, call.encode());
+
+		// a single member cannot reach the threshold
+		for signature in [
+			sign_with(&[&pairs[0]], &prop_data),
+			sign_with(&[&pairs[0], &pairs[0]], &prop_data),
+			sign_with(&[&pairs[0], &outsider], &prop_data),
+		] {
+			assert_err!(
+				Bridge::execute_proposal(
+					RuntimeOrigin::signed(RELAYER_A),
+					src_id,
+					prop_data.clone().try_into().unwrap(),
+					signature.try_into().unwrap(),
+				),
+				Error::<Test>::InvalidPermissions
+			);
+		}
+
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.try_into().unwrap(),
+			sign_with(&[&pairs[2], &pairs[0]], &prop_data).try_into().unwrap(),
+		));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded {
+			chain_id: src_id,
+			proposal_nonce: u32::from_be_bytes(nonce),
+		})]);
+	})
+}
+
+#[test]
+fn should_fail_to_set_invalid_maintainer_set() {
+	let pairs: Vec<ecdsa::Pair> = (0..2).map(|_| ecdsa::Pair::generate().0).collect();
+
+	new_test_ext().execute_with(|| {
+		for maintainers in [
+			make_maintainer_set(&pairs, 0, None),
+			make_maintainer_set(&pairs, 3, None),
+			make_maintainer_set(&[pairs[0].clone(), pairs[0].clone()], 1, None),
+			make_maintainer_set(&pairs, 1, Some(vec![1u8; 33])),
+		] {
+			assert_err!(
+				Bridge::force_set_maintainers(RuntimeOrigin::root(), 1u32, maintainers),
+				Error::<Test>::InvalidMaintainerSet
+			);
+		}
+		assert_eq!(Bridge::maintainer_set(), None);
+	})
+}
+
+#[test]
+fn set_maintainers_should_rotate_maintainer_set() {
+	let old_pairs: Vec<ecdsa::Pair> = (0..3).map(|_| ecdsa::Pair::generate().0).collect();
+	let new_pairs: Vec<ecdsa::Pair> = (0..2).map(|_| ecdsa::Pair::generate().0).collect();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainers(
+			RuntimeOrigin::root(),
+			0u32,
+			make_maintainer_set(&old_pairs, 2, None)
+		));
+
+		let new_maintainers = make_maintainer_set(&new_pairs, 1, None);
+		let message = maintainer_message(SET_MAINTAINERS_TAG, 1, &new_maintainers.encode());
+
+		// the handoff must be signed by the current set
+		assert_err!(
+			Bridge::set_maintainers(
+				RuntimeOrigin::signed(RELAYER_A),
+				message.clone().try_into().unwrap(),
+				sign_with(&[&new_pairs[0], &new_pairs[1]], &message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		assert_ok!(Bridge::set_maintainers(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&old_pairs[1], &old_pairs[2]], &message).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::maintainer_set(), Some(new_maintainers.clone()));
+		assert_eq!(MaintainerNonce::<Test>::get(), 1);
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::MaintainersSet {
+			maintainers: new_maintainers,
+		})]);
+
+		// the handoff cannot be replayed
+		assert_err!(
+			Bridge::set_maintainers(
+				RuntimeOrigin::signed(RELAYER_A),
+				message.clone().try_into().unwrap(),
+				sign_with(&[&old_pairs[1], &old_pairs[2]], &message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidNonce
+		);
+
+		// handing off to a single maintainer clears the set
+		let message = maintainer_message(SET_MAINTAINER_TAG, 2, &uncompressed_key(&old_pairs[0]));
+		assert_ok!(Bridge::set_maintainer(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&new_pairs[1]], &message).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::maintainer_set(), None);
+		assert_eq!(Bridge::maintainer(), uncompressed_key(&old_pairs[0]));
+		assert_eq!(Bridge::maintainer_threshold(), 1);
+	})
+}
+
+#[test]
+fn maintainer_messages_should_not_be_replayed_into_other_calls() {
+	let pair = ecdsa::Pair::generate().0;
+	let new_pair = ecdsa::Pair::generate().0;
+
+	new_test_ext().execute_with(|| {
+		Maintainer::<Test, _>::put(uncompressed_key(&pair));
+		let set_maintainer_message =
+			maintainer_message(SET_MAINTAINER_TAG, 1, &uncompressed_key(&new_pair));
+		let set_maintainers_message = maintainer_message(
+			SET_MAINTAINERS_TAG,
+			1,
+			&make_maintainer_set(&[new_pair.clone()], 1, None).encode(),
+		);
+		let set_typed_maintainer_message = maintainer_message(
+			SET_TYPED_MAINTAINER_TAG,
+			1,
+			&[KeyType::Ecdsa.encode(), uncompressed_key(&new_pair).into_inner()].concat(),
+		);
+		let update_bridge_status_message = maintainer_message(
+			UPDATE_BRIDGE_STATUS_TAG,
+			1,
+			&BridgeStatusUpdate::<u64>::RemoveChain(1).encode(),
+		);
+
+		// a signed handoff to a maintainer set is not a valid single maintainer handoff
+		assert_noop!(
+			Bridge::set_maintainer(
+				RuntimeOrigin::signed(RELAYER_A),
+				set_maintainers_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &set_maintainers_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		assert_noop!(
+			Bridge::set_maintainer(
+				RuntimeOrigin::signed(RELAYER_A),
+				set_typed_maintainer_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &set_typed_maintainer_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		assert_noop!(
+			Bridge::set_maintainer(
+				RuntimeOrigin::signed(RELAYER_A),
+				update_bridge_status_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &update_bridge_status_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		assert_noop!(
+			Bridge::update_bridge_status(
+				RuntimeOrigin::signed(RELAYER_A),
+				set_typed_maintainer_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &set_typed_maintainer_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		assert_noop!(
+			Bridge::set_typed_maintainer(
+				RuntimeOrigin::signed(RELAYER_A),
+				set_maintainers_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &set_maintainers_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		// and the other way around
+		assert_noop!(
+			Bridge::set_maintainers(
+				RuntimeOrigin::signed(RELAYER_A),
+				set_maintainer_message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &set_maintainer_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerMessage
+		);
+		// single maintainer handoffs must be tagged and hold a key
+		let untagged_message = maintainer_message(&[], 1, &uncompressed_key(&new_pair));
+		let empty_message = maintainer_message(SET_MAINTAINER_TAG, 1, &[]);
+		for message in [untagged_message, empty_message] {
+			assert_noop!(
+				Bridge::set_maintainer(
+					RuntimeOrigin::signed(RELAYER_A),
+					message.clone().try_into().unwrap(),
+					sign_with(&[&pair], &message).try_into().unwrap(),
+				),
+				Error::<Test>::InvalidMaintainerMessage
+			);
+		}
+		assert_ok!(Bridge::set_maintainer(
+			RuntimeOrigin::signed(RELAYER_A),
+			set_maintainer_message.clone().try_into().unwrap(),
+			sign_with(&[&pair], &set_maintainer_message).try_into().unwrap(),
+		));
+	})
+}
+
+#[test]
+fn aggregate_schnorr_signature_should_execute_proposal() {
+	use k256::schnorr::{signature::hazmat::PrehashSigner, SigningKey};
+
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pairs: Vec<ecdsa::Pair> = (0..3).map(|_| ecdsa::Pair::generate().0).collect();
+	// stands in for the group key of a FROST signer set run by the maintainers
+	let group_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let aggregate_key = group_key.verifying_key().to_bytes().to_vec();
+		assert_ok!(Bridge::force_set_maintainers(
+			RuntimeOrigin::root(),
+			1u32,
+			make_maintainer_set(&pairs, 3, Some(aggregate_key.clone()))
+		));
+		assert_eq!(Bridge::maintainer_aggregate_key(), Some(aggregate_key));
+
+		let call = make_proposal(vec![10]);
+		let nonce = [0u8, 0u8, 0u8, 1u8];
+		let prop_data = make_proposal_data(r_id.encode(), nonce, call.encode());
+		let signature: k256::schnorr::Signature =
+			group_key.sign_prehash(&keccak_256(&prop_data)).unwrap();
+		let mut tampered = signature.to_bytes().to_vec();
+		tampered[0] ^= 1;
+
+		assert_err!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				tampered.try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.try_into().unwrap(),
+			signature.to_bytes().to_vec().try_into().unwrap(),
+		));
+	})
+}
+
+#[test]
+fn substrate_native_maintainers_should_execute_proposal() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let sr25519_pair = sp_core::sr25519::Pair::generate().0;
+	let ed25519_pair = sp_core::ed25519::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let call = make_proposal(vec![10]);
+		let prop_data = make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 1u8], call.encode());
+
+		assert_ok!(Bridge::force_set_typed_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			KeyType::Sr25519,
+			sr25519_pair.public().0.to_vec().try_into().unwrap()
+		));
+		assert_eq!(Bridge::maintainer_key_type(), KeyType::Sr25519);
+		// an ed25519 signature does not verify against an sr25519 maintainer
+		assert_err!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				ed25519_pair.sign(&prop_data).0.to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.try_into().unwrap(),
+			sr25519_pair.sign(&prop_data).0.to_vec().try_into().unwrap(),
+		));
+
+		assert_ok!(Bridge::force_set_typed_maintainer(
+			RuntimeOrigin::root(),
+			2u32,
+			KeyType::Ed25519,
+			ed25519_pair.public().0.to_vec().try_into().unwrap()
+		));
+		let prop_data = make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 2u8], call.encode());
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			ed25519_pair.sign(&prop_data).0.to_vec().try_into().unwrap(),
+		));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded {
+			chain_id: src_id,
+			proposal_nonce: 2,
+		})]);
+	})
+}
+
+#[test]
+fn set_typed_maintainer_should_hand_off_to_schnorr_key() {
+	use k256::schnorr::{signature::hazmat::PrehashSigner, SigningKey};
+
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let ecdsa_pair = ecdsa::Pair::generate().0;
+	let schnorr_key = SigningKey::from_bytes(&[9u8; 32]).unwrap();
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		Maintainer::<Test, _>::put(uncompressed_key(&ecdsa_pair));
+		let new_maintainer = schnorr_key.verifying_key().to_bytes().to_vec();
+
+		// the key must match its key type
+		let message = maintainer_message(
+			SET_TYPED_MAINTAINER_TAG,
+			1,
+			&[KeyType::Ecdsa.encode(), new_maintainer.clone()].concat(),
+		);
+		assert_err!(
+			Bridge::set_typed_maintainer(
+				RuntimeOrigin::signed(RELAYER_A),
+				message.clone().try_into().unwrap(),
+				sign_with(&[&ecdsa_pair], &message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidMaintainerKey
+		);
+
+		let message = maintainer_message(
+			SET_TYPED_MAINTAINER_TAG,
+			1,
+			&[KeyType::Schnorr.encode(), new_maintainer.clone()].concat(),
+		);
+		assert_ok!(Bridge::set_typed_maintainer(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&ecdsa_pair], &message).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::maintainer_key_type(), KeyType::Schnorr);
+		assert_eq!(Bridge::maintainer().into_inner(), new_maintainer);
+
+		let call = make_proposal(vec![10]);
+		let prop_data = make_proposal_data(r_id.encode(), [0u8, 0u8, 0u8, 1u8], call.encode());
+		let signature: k256::schnorr::Signature =
+			schnorr_key.sign_prehash(&keccak_256(&prop_data)).unwrap();
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.try_into().unwrap(),
+			signature.to_bytes().to_vec().try_into().unwrap(),
+		));
+
+		// setting an untyped maintainer goes back to ECDSA
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			2u32,
+			uncompressed_key(&ecdsa_pair)
+		));
+		assert_eq!(Bridge::maintainer_key_type(), KeyType::Ecdsa);
+	})
+}
+
+#[test]
+fn admin_calls_should_reject_unprivileged_origins() {
+	let id = ResourceId([1; 32]);
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_resource(RuntimeOrigin::root(), id));
+
+		for origin in [RuntimeOrigin::signed(RELAYER_A), RuntimeOrigin::none()] {
+			assert_noop!(
+				Bridge::force_set_maintainer(origin.clone(), 1u32, uncompressed_key(&pair)),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Bridge::force_set_maintainers(
+					origin.clone(),
+					1u32,
+					make_maintainer_set(&[pair.clone()], 1, None)
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Bridge::force_set_typed_maintainer(
+					origin.clone(),
+					1u32,
+					KeyType::Ecdsa,
+					uncompressed_key(&pair)
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Bridge::set_resource(origin.clone(), ResourceId([2; 32])),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(Bridge::remove_resource(origin.clone(), id), DispatchError::BadOrigin);
+			assert_noop!(Bridge::whitelist_chain(origin, 1u64), DispatchError::BadOrigin);
+		}
+
+		assert!(Bridge::resource_exists(id));
+		assert!(!Bridge::chain_whitelisted(1u64));
+		assert!(Bridge::maintainer().is_empty());
+	})
+}
+
+#[test]
+fn execute_proposal_should_reject_replayed_and_stale_nonces() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let other_r_id =
+		derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 2 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::set_resource(RuntimeOrigin::root(), other_r_id));
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let execute = |r_id: ResourceId, nonce: u32| {
+			let prop_data = make_proposal_data(
+				r_id.encode(),
+				nonce.to_be_bytes(),
+				make_proposal(vec![10]).encode(),
+			);
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			)
+		};
+
+		assert_ok!(execute(r_id, 5));
+		assert_eq!(Bridge::resource_nonce(r_id), 5);
+		// the same proposal cannot be replayed
+		assert_err!(execute(r_id, 5), Error::<Test>::InvalidNonce);
+		// nonces cannot go backwards
+		assert_err!(execute(r_id, 4), Error::<Test>::InvalidNonce);
+		// nonces cannot jump by more than 1,048
+		assert_err!(execute(r_id, 5 + 1_049), Error::<Test>::InvalidNonce);
+		assert_ok!(execute(r_id, 6));
+		// every resource has its own nonce
+		assert_ok!(execute(other_r_id, 1));
+		assert_eq!(Bridge::resource_nonce(r_id), 6);
+		assert_eq!(Bridge::resource_nonce(other_r_id), 1);
+		// executing proposals does not move the set resource nonce
+		assert_eq!(Bridge::proposal_nonce(), 0);
+	})
+}
+
+#[test]
+fn execute_proposal_should_reject_call_for_another_resource() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let other_r_id =
+		derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 2 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		mock::MatchingResource::set(Some(other_r_id));
+
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 1u8],
+			make_proposal(vec![10]).encode(),
+		);
+		assert_err!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			),
+			Error::<Test>::CallDoesNotMatchResourceId
+		);
+		assert_eq!(Bridge::resource_nonce(r_id), 0);
+	})
+}
+
+#[test]
+fn execute_proposal_batch_should_report_failed_proposals() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let other_r_id =
+		derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 2 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::set_resource(RuntimeOrigin::root(), other_r_id));
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let call = make_proposal(vec![10]).encode();
+		let proposals = vec![
+			make_proposal_data(r_id.encode(), 1u32.to_be_bytes(), call.clone()),
+			// replays the nonce of the first proposal
+			make_proposal_data(r_id.encode(), 1u32.to_be_bytes(), call.clone()),
+			make_proposal_data(other_r_id.encode(), 1u32.to_be_bytes(), call),
+		];
+		let (root, proofs) = Bridge::build_proposal_batch(&proposals);
+		let batch: Vec<BatchedProposalOf<Test, ()>> = proposals
+			.into_iter()
+			.zip(proofs)
+			.map(|(proposal_data, proof)| BatchedProposal {
+				proposal_data: proposal_data.try_into().unwrap(),
+				proof: proof.try_into().unwrap(),
+			})
+			.collect();
+
+		// the root must be signed by the maintainer
+		let batch_message = [PROPOSAL_BATCH_TAG, &root[..]].concat();
+		let outsider = ecdsa::Pair::generate().0;
+		assert_noop!(
+			Bridge::execute_proposal_batch(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				batch.clone().try_into().unwrap(),
+				root,
+				sign_with(&[&outsider], &batch_message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		// and under the batch tag
+		assert_noop!(
+			Bridge::execute_proposal_batch(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				batch.clone().try_into().unwrap(),
+				root,
+				sign_with(&[&pair], &root).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+
+		assert_ok!(Bridge::execute_proposal_batch(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			batch.try_into().unwrap(),
+			root,
+			sign_with(&[&pair], &batch_message).try_into().unwrap(),
+		));
+		assert_events(vec![
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalApproved {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::BatchedProposalFailed {
+				chain_id: src_id,
+				index: 1,
+				error: Error::<Test>::InvalidNonce.into(),
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalApproved {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalBatchExecuted {
+				chain_id: src_id,
+				root,
+				failed: 1,
+			}),
+		]);
+		assert_eq!(Bridge::resource_nonce(r_id), 1);
+		assert_eq!(Bridge::resource_nonce(other_r_id), 1);
+	})
+}
+
+#[test]
+fn execute_proposal_batch_should_reject_proposals_outside_the_batch() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let call = make_proposal(vec![10]).encode();
+		let signed = vec![
+			make_proposal_data(r_id.encode(), 1u32.to_be_bytes(), call.clone()),
+			make_proposal_data(r_id.encode(), 2u32.to_be_bytes(), call.clone()),
+		];
+		let (root, proofs) = Bridge::build_proposal_batch(&signed);
+		// a proposal that was not signed, presented with the proof of a signed one
+		let unsigned = make_proposal_data(r_id.encode(), 3u32.to_be_bytes(), call);
+		let batch = vec![BatchedProposal {
+			proposal_data: unsigned.try_into().unwrap(),
+			proof: proofs[1].clone().try_into().unwrap(),
+		}];
+
+		let post_info = Bridge::execute_proposal_batch(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			batch.try_into().unwrap(),
+			root,
+			sign_with(&[&pair], &[PROPOSAL_BATCH_TAG, &root[..]].concat())
+				.try_into()
+				.unwrap(),
+		)
+		.unwrap();
+		// the weight of the call that was not executed is refunded
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::execute_proposal_batch(1)));
+		assert_events(vec![
+			RuntimeEvent::Bridge(pallet_bridge::Event::BatchedProposalFailed {
+				chain_id: src_id,
+				index: 0,
+				error: Error::<Test>::InvalidInclusionProof.into(),
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalBatchExecuted {
+				chain_id: src_id,
+				root,
+				failed: 1,
+			}),
+		]);
+		assert_eq!(Bridge::resource_nonce(r_id), 0);
+	})
+}
+
+#[test]
+fn sensitive_proposals_should_be_timelocked() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		mock::QueueAllProposals::set(true);
+		frame_system::Pallet::<Test>::set_block_number(1);
+
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 1u8],
+			make_proposal(vec![10]).encode(),
+		);
+		let proposal_hash = keccak_256(&prop_data);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::ProposalQueued {
+			chain_id: src_id,
+			proposal_nonce: 1,
+			proposal_hash,
+			execute_after: 11,
+		})]);
+		// the nonce is consumed when the proposal is queued
+		assert_eq!(Bridge::resource_nonce(r_id), 1);
+		assert_eq!(Bridge::proposal_executed_at(proposal_hash), None);
+
+		let call_weight = Bridge::proposal_call_weight(&prop_data);
+		frame_system::Pallet::<Test>::set_block_number(10);
+		assert_noop!(
+			Bridge::execute_queued_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				proposal_hash,
+				call_weight
+			),
+			Error::<Test>::ProposalStillTimelocked
+		);
+
+		frame_system::Pallet::<Test>::set_block_number(11);
+		// the weight limit must cover the weight of the queued call
+		assert_noop!(
+			Bridge::execute_queued_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				proposal_hash,
+				Weight::zero()
+			),
+			Error::<Test>::ProposalWeightTooHigh
+		);
+		let post_info = Bridge::execute_queued_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			proposal_hash,
+			call_weight,
+		)
+		.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::execute_queued_proposal().saturating_add(call_weight))
+		);
+		assert_events(vec![
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalApproved {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+			RuntimeEvent::Bridge(pallet_bridge::Event::ProposalSucceeded {
+				chain_id: src_id,
+				proposal_nonce: 1,
+			}),
+		]);
+		assert!(Bridge::queued_proposals(proposal_hash).is_none());
+		assert_eq!(Bridge::proposal_executed_at(proposal_hash), Some(11));
+		// a queued proposal can only be executed once
+		assert_noop!(
+			Bridge::execute_queued_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				proposal_hash,
+				Weight::zero()
+			),
+			Error::<Test>::ProposalNotQueued
+		);
+	})
+}
+
+#[test]
+fn queued_proposals_should_be_cancellable_by_admin() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		mock::QueueAllProposals::set(true);
+		frame_system::Pallet::<Test>::set_block_number(1);
+
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 1u8],
+			make_proposal(vec![10]).encode(),
+		);
+		let proposal_hash = keccak_256(&prop_data);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+
+		assert_noop!(
+			Bridge::cancel_queued_proposal(RuntimeOrigin::signed(RELAYER_A), proposal_hash),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Bridge::cancel_queued_proposal(RuntimeOrigin::root(), proposal_hash));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::QueuedProposalCancelled {
+			proposal_hash,
+		})]);
+
+		frame_system::Pallet::<Test>::set_block_number(11);
+		assert_noop!(
+			Bridge::execute_queued_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				proposal_hash,
+				Weight::zero()
+			),
+			Error::<Test>::ProposalNotQueued
+		);
+		// the cancelled proposal cannot be resubmitted with the same nonce
+		assert_noop!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidNonce
+		);
+	})
+}
+
+#[test]
+fn executed_proposals_should_be_recorded_with_their_block() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		frame_system::Pallet::<Test>::set_block_number(7);
+
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 1u8],
+			make_proposal(vec![10]).encode(),
+		);
+		let proposal_hash = keccak_256(&prop_data);
+		assert_eq!(Bridge::proposal_executed_at(proposal_hash), None);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::proposal_executed_at(proposal_hash), Some(7));
+
+		// proposals that fail are not recorded
+		let bad_prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 2u8],
+			make_proposal(vec![10]).encode(),
+		);
+		assert!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			bad_prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		)
+		.is_err());
+		assert_eq!(Bridge::proposal_executed_at(keccak_256(&bad_prop_data)), None);
+
+		assert_eq!(Bridge::whitelisted_chains(), vec![(src_id, 0)]);
+		assert_eq!(Bridge::registered_resources(), vec![(r_id, 1)]);
+	})
+}
+
+#[test]
+fn executed_proposals_history_should_prune_the_oldest_proposal() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		// the mock remembers the last 3 executed proposals
+		let proposal_hashes: Vec<[u8; 32]> = (1u32..=4)
+			.map(|nonce| {
+				let prop_data = make_proposal_data(
+					r_id.encode(),
+					nonce.to_be_bytes(),
+					make_proposal(vec![10]).encode(),
+				);
+				assert_ok!(Bridge::execute_proposal(
+					RuntimeOrigin::signed(RELAYER_A),
+					src_id,
+					prop_data.clone().try_into().unwrap(),
+					sign_with(&[&pair], &prop_data).try_into().unwrap(),
+				));
+				keccak_256(&prop_data)
+			})
+			.collect();
+
+		assert_eq!(Bridge::proposal_executed_at(proposal_hashes[0]), None);
+		for proposal_hash in &proposal_hashes[1..] {
+			assert_eq!(Bridge::proposal_executed_at(*proposal_hash), Some(1));
+		}
+		assert_eq!(pallet_bridge::NextExecutedProposalIndex::<Test>::get(), 1);
+	})
+}
+
+#[test]
+fn empty_executed_proposals_history_should_not_record_proposals() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		mock::ExecutedProposalHistorySize::set(0);
+		let prop_data =
+			make_proposal_data(r_id.encode(), 1u32.to_be_bytes(), make_proposal(vec![10]).encode());
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::proposal_executed_at(keccak_256(&prop_data)), None);
+		assert_eq!(pallet_bridge::NextExecutedProposalIndex::<Test>::get(), 0);
+	})
+}
+
+#[test]
+fn paused_resources_and_removed_chains_should_reject_proposals() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let execute = |nonce: u32| {
+			let prop_data = make_proposal_data(
+				r_id.encode(),
+				nonce.to_be_bytes(),
+				make_proposal(vec![10]).encode(),
+			);
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			)
+		};
+
+		assert_noop!(
+			Bridge::pause_resource(RuntimeOrigin::signed(RELAYER_A), r_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Bridge::pause_resource(RuntimeOrigin::root(), r_id));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::ResourcePaused {
+			resource_id: r_id,
+		})]);
+		assert_noop!(
+			Bridge::pause_resource(RuntimeOrigin::root(), r_id),
+			Error::<Test>::ResourcePaused
+		);
+		assert_noop!(execute(1), Error::<Test>::ResourcePaused);
+
+		assert_ok!(Bridge::unpause_resource(RuntimeOrigin::root(), r_id));
+		assert_noop!(
+			Bridge::unpause_resource(RuntimeOrigin::root(), r_id),
+			Error::<Test>::ResourceNotPaused
+		);
+		assert_ok!(execute(1));
+
+		assert_noop!(
+			Bridge::remove_chain(RuntimeOrigin::signed(RELAYER_A), src_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Bridge::remove_chain(RuntimeOrigin::root(), src_id));
+		assert_events(vec![RuntimeEvent::Bridge(pallet_bridge::Event::ChainRemoved {
+			chain_id: src_id,
+		})]);
+		assert!(!Bridge::chain_whitelisted(src_id));
+		assert_noop!(execute(2), Error::<Test>::ChainNotWhitelisted);
+		assert_noop!(
+			Bridge::remove_chain(RuntimeOrigin::root(), src_id),
+			Error::<Test>::ChainNotWhitelisted
+		);
+	})
+}
+
+#[test]
+fn update_bridge_status_should_require_maintainer_signature() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+	let other_pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let status_message = |nonce: u32, update: BridgeStatusUpdate<u64>| {
+			maintainer_message(UPDATE_BRIDGE_STATUS_TAG, nonce, &update.encode())
+		};
+
+		let message = status_message(2, BridgeStatusUpdate::PauseResource(r_id));
+		assert_noop!(
+			Bridge::update_bridge_status(
+				RuntimeOrigin::signed(RELAYER_A),
+				message.clone().try_into().unwrap(),
+				sign_with(&[&other_pair], &message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		assert_ok!(Bridge::update_bridge_status(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&pair], &message).try_into().unwrap(),
+		));
+		assert!(Bridge::resource_paused(r_id));
+		// the same update cannot be replayed
+		assert_noop!(
+			Bridge::update_bridge_status(
+				RuntimeOrigin::signed(RELAYER_A),
+				message.clone().try_into().unwrap(),
+				sign_with(&[&pair], &message).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidNonce
+		);
+
+		let message = status_message(3, BridgeStatusUpdate::UnpauseResource(r_id));
+		assert_ok!(Bridge::update_bridge_status(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&pair], &message).try_into().unwrap(),
+		));
+		assert!(!Bridge::resource_paused(r_id));
+
+		let message = status_message(4, BridgeStatusUpdate::RemoveChain(src_id));
+		assert_ok!(Bridge::update_bridge_status(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.clone().try_into().unwrap(),
+			sign_with(&[&pair], &message).try_into().unwrap(),
+		));
+		assert!(!Bridge::chain_whitelisted(src_id));
+	})
+}
+
+#[test]
+fn typed_proposals_should_be_mapped_to_their_call() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let typed_proposal = |function_signature: FunctionSignature, nonce: u32, body: &[u8]| {
+			let mut prop_data = r_id.encode();
+			prop_data.extend_from_slice(&function_signature.0);
+			prop_data.extend_from_slice(&nonce.to_be_bytes());
+			prop_data.extend_from_slice(body);
+			prop_data
+		};
+
+		// the body of a typed proposal is not a SCALE encoded call
+		let prop_data = typed_proposal(mock::REMARK_FUNCTION_SIG, 1, &[10]);
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+		assert_eq!(Bridge::resource_nonce(r_id), 1);
+
+		// unknown function signatures fall back to a SCALE encoded call
+		let prop_data =
+			typed_proposal(FunctionSignature([0, 0, 0, 7]), 2, &make_proposal(vec![10]).encode());
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sign_with(&[&pair], &prop_data).try_into().unwrap(),
+		));
+		let prop_data = typed_proposal(FunctionSignature([0, 0, 0, 7]), 3, &[10]);
+		assert_noop!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidCall
+		);
+	})
+}
+
+#[test]
+fn successful_proposals_should_be_free_to_relay() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		let execute = |nonce: u32| {
+			let prop_data = make_proposal_data(
+				r_id.encode(),
+				nonce.to_be_bytes(),
+				make_proposal(vec![10]).encode(),
+			);
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			)
+		};
+
+		let post_info = execute(1).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		// the declared weight of the call is charged, less what it reports as unused
+		let call_weight = make_proposal(vec![10]).get_dispatch_info().weight;
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::execute_proposal().saturating_add(call_weight))
+		);
+		// replaying the proposal fails and is charged
+		let error = execute(1).unwrap_err();
+		assert_eq!(error.post_info.pays_fee, Pays::Yes);
+		assert_eq!(error.error, Error::<Test>::InvalidNonce.into());
+	})
+}
+
+#[test]
+fn domain_separated_signatures_should_execute_proposal() {
+	let chain_type = [2, 0];
+	let src_id = compute_chain_id_type(1u32, chain_type);
+	let r_id = derive_resource_id(1080u32, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+	let pair = ecdsa::Pair::generate().0;
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		assert_ok!(Bridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			uncompressed_key(&pair)
+		));
+		mock::SignWithEip712::set(true);
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			[0u8, 0u8, 0u8, 1u8],
+			make_proposal(vec![10]).encode(),
+		);
+
+		// a signature over the bare proposal hash is not valid in the domain
+		assert_noop!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sign_with(&[&pair], &prop_data).try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+		// nor is one made for another bridge instance
+		let other_domain = eip712_domain_separator(
+			b"Webb Signature Bridge",
+			b"1",
+			compute_chain_id_type(1080u32, chain_type),
+			keccak_256(b"another bridge"),
+		);
+		let sig = pair.sign_prehashed(&eip712_hash(&other_domain, &prop_data).unwrap());
+		assert_noop!(
+			Bridge::execute_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				src_id,
+				prop_data.clone().try_into().unwrap(),
+				sig.0.to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPermissions
+		);
+
+		let domain = mock::ProposalSigningDomain::get();
+		let sig = pair.sign_prehashed(&eip712_hash(&domain, &prop_data).unwrap());
+		assert_ok!(Bridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			prop_data.clone().try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+
+		// maintainer messages are signed as the struct of their call
+		let new_pair = ecdsa::Pair::generate().0;
+		let new_maintainers = make_maintainer_set(&[new_pair.clone()], 1, None);
+		let message = maintainer_message(SET_MAINTAINERS_TAG, 2, &new_maintainers.encode());
+		let sig = pair.sign_prehashed(&eip712_hash(&domain, &message).unwrap());
+		assert_ok!(Bridge::set_maintainers(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		assert_eq!(Bridge::maintainer_set(), Some(new_maintainers));
+		let message = maintainer_message(SET_MAINTAINER_TAG, 3, &uncompressed_key(&pair));
+		let sig = new_pair.sign_prehashed(&eip712_hash(&domain, &message).unwrap());
+		assert_ok!(Bridge::set_maintainer(
+			RuntimeOrigin::signed(RELAYER_A),
+			message.try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		assert_eq!(Bridge::maintainer(), uncompressed_key(&pair));
+
+		// `personal_sign` signatures are checked by the EIP-191 verifier
+		let sig = pair.sign_prehashed(&eip191_hash(&prop_data));
+		assert!(
+			Eip191SignatureVerifier::verify(&uncompressed_key(&pair), &prop_data, &sig.0).unwrap()
+		);
+		assert!(!webb_primitives::signing::SignatureVerifier::verify(
+			&uncompressed_key(&pair),
+			&prop_data,
+			&sig.0
+		)
+		.unwrap());
+	})
+}
@@ -0,0 +1,113 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::fmt::Debug;
+pub use webb_primitives::signing::{
+	PROPOSAL_BATCH_TAG, SET_MAINTAINERS_TAG, SET_MAINTAINER_TAG, SET_TYPED_MAINTAINER_TAG,
+	UPDATE_BRIDGE_STATUS_TAG,
+};
+use webb_primitives::{signing::schnorr, webb_proposals::ResourceId};
+
+/// A set of maintainers that jointly sign proposals.
+///
+/// A signature is accepted if it is made of `threshold` concatenated ECDSA
+/// signatures from distinct members, or is a Schnorr signature of the
+/// aggregate key of the members.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(KeyLimit, MaxMembers))]
+#[codec(mel_bound())]
+pub struct MaintainerSet<KeyLimit: Get<u32>, MaxMembers: Get<u32>> {
+	/// Uncompressed ECDSA public keys of the members, without the 0x04 prefix
+	pub members: BoundedVec<BoundedVec<u8, KeyLimit>, MaxMembers>,
+	/// The number of distinct member signatures a proposal needs
+	pub threshold: u32,
+	/// The x-only group key of a threshold Schnorr scheme run by the members
+	pub aggregate_key: Option<BoundedVec<u8, KeyLimit>>,
+}
+
+impl<KeyLimit: Get<u32>, MaxMembers: Get<u32>> MaintainerSet<KeyLimit, MaxMembers> {
+	/// Checks that the threshold can be reached, that no member is repeated and
+	/// that the aggregate key, if any, is an x-only public key
+	pub fn is_valid(&self) -> bool {
+		let threshold = self.threshold as usize;
+		threshold > 0 &&
+			self.aggregate_key
+				.as_ref()
+				.map_or(true, |key| key.len() == schnorr::PUBLIC_KEY_LENGTH) &&
+			threshold <= self.members.len() &&
+			self.members
+				.iter()
+				.enumerate()
+				.all(|(i, member)| !self.members[..i].contains(member))
+	}
+}
+
+/// A proposal of a signed batch together with the proof of its inclusion in the batch
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxProposalLength, MaxProofLength))]
+#[codec(mel_bound())]
+pub struct BatchedProposal<MaxProposalLength: Get<u32>, MaxProofLength: Get<u32>> {
+	/// The proposal data: (r_id, nonce, 4 bytes of zeroes, call)
+	pub proposal_data: BoundedVec<u8, MaxProposalLength>,
+	/// The sibling hashes from the leaf of the proposal up to the root of the batch
+	pub proof: BoundedVec<[u8; 32], MaxProofLength>,
+}
+
+/// A sensitive proposal waiting for its timelock to expire
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxProposalLength))]
+#[codec(mel_bound(ChainId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct QueuedProposal<ChainId, BlockNumber, MaxProposalLength: Get<u32>>
+where
+	ChainId: Clone + Eq + Debug,
+	BlockNumber: Clone + Eq + Debug,
+{
+	/// The chain the proposal was received from
+	pub src_id: ChainId,
+	/// The proposal data: (r_id, nonce, 4 bytes of zeroes, call)
+	pub proposal_data: BoundedVec<u8, MaxProposalLength>,
+	/// The block from which the proposal can be executed
+	pub execute_after: BlockNumber,
+}
+
+/// A change to the chains and resources the bridge accepts proposals from,
+/// signed by the maintainer(s).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BridgeStatusUpdate<ChainId> {
+	/// Stop accepting proposals from a whitelisted chain
+	RemoveChain(ChainId),
+	/// Stop accepting proposals for a resource
+	PauseResource(ResourceId),
+	/// Resume accepting proposals for a paused resource
+	UnpauseResource(ResourceId),
+}
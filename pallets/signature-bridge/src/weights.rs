@@ -21,6 +21,9 @@
 //! DATE: 2022-06-14, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: ``, CPU: ``
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Functions marked as placeholders were added or changed by hand after this run and
+//! must be replaced by the output of the next benchmark run.
 
 // Executed Command:
 // ./target/release/webb-standalone-node
@@ -53,6 +56,17 @@ pub trait WeightInfo {
 	fn whitelist_chain() -> Weight;
 	fn set_resource_with_signature() -> Weight;
 	fn execute_proposal() -> Weight;
+	fn set_maintainers() -> Weight;
+	fn force_set_maintainers() -> Weight;
+	fn set_typed_maintainer() -> Weight;
+	fn force_set_typed_maintainer() -> Weight;
+	fn execute_proposal_batch(n: u32, ) -> Weight;
+	fn execute_queued_proposal() -> Weight;
+	fn cancel_queued_proposal() -> Weight;
+	fn remove_chain() -> Weight;
+	fn pause_resource() -> Weight;
+	fn unpause_resource() -> Weight;
+	fn update_bridge_status() -> Weight;
 }
 
 /// Weights for pallet_signature_bridge using the Substrate node and recommended hardware.
@@ -91,20 +105,132 @@ impl<T: frame_system::Config> WeightInfo for WebbWeight<T> {
 	// Storage: SignatureBridge Maintainer (r:1 w:0)
 	// Storage: SignatureBridge ChainNonces (r:1 w:0)
 	// Storage: SignatureBridge Resources (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
 	fn set_resource_with_signature() -> Weight {
 		Weight::from_ref_time(61_000_000)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
-	// Storage: SignatureBridge ProposalNonce (r:1 w:1)
+	// Storage: SignatureBridge ResourceNonces (r:1 w:1)
 	// Storage: SignatureBridge Maintainer (r:1 w:0)
 	// Storage: SignatureBridge ChainNonces (r:1 w:0)
 	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
 	fn execute_proposal() -> Weight {
 		Weight::from_ref_time(59_000_000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:1)
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_maintainers() -> Weight {
+		Weight::from_ref_time(138_000_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:0 w:1)
+	// Storage: SignatureBridge Maintainers (r:0 w:1)
+	// Storage: SignatureBridge Maintainer (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn force_set_maintainers() -> Weight {
+		Weight::from_ref_time(14_000_000)
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:1)
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Storage: SignatureBridge MaintainerKeyType (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_typed_maintainer() -> Weight {
+		Weight::from_ref_time(54_000_000)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Storage: SignatureBridge MaintainerNonce (r:0 w:1)
+	// Storage: SignatureBridge Maintainers (r:0 w:1)
+	// Storage: SignatureBridge MaintainerKeyType (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn force_set_typed_maintainer() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: SignatureBridge Maintainers (r:1 w:0)
+	// Storage: SignatureBridge MaintainerKeyType (r:1 w:0)
+	// Storage: SignatureBridge Maintainer (r:1 w:0)
+	// Storage: SignatureBridge ChainNonces (r:1 w:0)
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge ResourceNonces (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn execute_proposal_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(58_000_000)
+			.saturating_add(Weight::from_ref_time(24_000_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	// Storage: SignatureBridge QueuedProposals (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn execute_queued_proposal() -> Weight {
+		Weight::from_ref_time(37_000_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: SignatureBridge QueuedProposals (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn cancel_queued_proposal() -> Weight {
+		Weight::from_ref_time(14_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge ChainNonces (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn remove_chain() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn pause_resource() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn unpause_resource() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:0)
+	// Storage: SignatureBridge Maintainer (r:1 w:0)
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn update_bridge_status() -> Weight {
+		Weight::from_ref_time(56_000_000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -142,18 +268,130 @@ impl WeightInfo for () {
 	// Storage: SignatureBridge Maintainer (r:1 w:0)
 	// Storage: SignatureBridge ChainNonces (r:1 w:0)
 	// Storage: SignatureBridge Resources (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
 	fn set_resource_with_signature() -> Weight {
 		Weight::from_ref_time(61_000_000)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
-	// Storage: SignatureBridge ProposalNonce (r:1 w:1)
+	// Storage: SignatureBridge ResourceNonces (r:1 w:1)
 	// Storage: SignatureBridge Maintainer (r:1 w:0)
 	// Storage: SignatureBridge ChainNonces (r:1 w:0)
 	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
 	fn execute_proposal() -> Weight {
 		Weight::from_ref_time(59_000_000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:1)
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_maintainers() -> Weight {
+		Weight::from_ref_time(138_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:0 w:1)
+	// Storage: SignatureBridge Maintainers (r:0 w:1)
+	// Storage: SignatureBridge Maintainer (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn force_set_maintainers() -> Weight {
+		Weight::from_ref_time(14_000_000)
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:1)
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Storage: SignatureBridge MaintainerKeyType (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_typed_maintainer() -> Weight {
+		Weight::from_ref_time(54_000_000)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: SignatureBridge Maintainer (r:1 w:1)
+	// Storage: SignatureBridge MaintainerNonce (r:0 w:1)
+	// Storage: SignatureBridge Maintainers (r:0 w:1)
+	// Storage: SignatureBridge MaintainerKeyType (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn force_set_typed_maintainer() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: SignatureBridge Maintainers (r:1 w:0)
+	// Storage: SignatureBridge MaintainerKeyType (r:1 w:0)
+	// Storage: SignatureBridge Maintainer (r:1 w:0)
+	// Storage: SignatureBridge ChainNonces (r:1 w:0)
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge ResourceNonces (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn execute_proposal_batch(n: u32, ) -> Weight {
+		Weight::from_ref_time(58_000_000)
+			.saturating_add(Weight::from_ref_time(24_000_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	// Storage: SignatureBridge QueuedProposals (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposals (r:0 w:2)
+	// Storage: SignatureBridge NextExecutedProposalIndex (r:1 w:1)
+	// Storage: SignatureBridge ExecutedProposalHistory (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn execute_queued_proposal() -> Weight {
+		Weight::from_ref_time(37_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: SignatureBridge QueuedProposals (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn cancel_queued_proposal() -> Weight {
+		Weight::from_ref_time(14_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge ChainNonces (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn remove_chain() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn pause_resource() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn unpause_resource() -> Weight {
+		Weight::from_ref_time(13_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: SignatureBridge MaintainerNonce (r:1 w:1)
+	// Storage: SignatureBridge Maintainers (r:1 w:0)
+	// Storage: SignatureBridge Maintainer (r:1 w:0)
+	// Storage: SignatureBridge Resources (r:1 w:0)
+	// Storage: SignatureBridge PausedResources (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn update_bridge_status() -> Weight {
+		Weight::from_ref_time(56_000_000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
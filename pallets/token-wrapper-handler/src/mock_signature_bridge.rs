@@ -12,7 +12,7 @@ use orml_currencies::{BasicCurrencyAdapter, NativeCurrencyOf};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{AccountIdConversion, BlakeTwo256, ConstU32, IdentityLookup},
+	traits::{AccountIdConversion, BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
 	Permill,
 };
 use sp_std::convert::{TryFrom, TryInto};
@@ -237,11 +237,18 @@ impl pallet_signature_bridge::Config<BridgeInstance> for Test {
 	type ChainIdentifier = ChainIdentifier;
 	type ChainType = ChainType;
 	type MaxStringLength = ConstU32<1000>;
+	type MaxMaintainers = ConstU32<10>;
+	type MaxProposalsPerBatch = ConstU32<32>;
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalLifetime = ProposalLifetime;
+	type ExecutedProposalHistorySize = ConstU32<100>;
+	type ProposalDelay = ConstU64<0>;
 	type ProposalNonce = ProposalNonce;
 	type SetResourceProposalFilter = SetResourceProposalFilter;
 	type ExecuteProposalFilter = ExecuteProposalFilter;
+	type ProposalResourceMatcher = frame_support::traits::Everything;
+	type SensitiveProposalFilter = frame_support::traits::Nothing;
+	type ProposalDecoder = ();
 	type MaintainerNonce = MaintainerNonce;
 	type SignatureVerifier = webb_primitives::signing::SignatureVerifier;
 	type WeightInfo = ();
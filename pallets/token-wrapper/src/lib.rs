@@ -352,7 +352,7 @@ impl<T: Config> Pallet<T> {
 		into_pool_share_id: T::AssetId,
 	) -> bool {
 		let total = Self::get_amount_to_wrap(amount, into_pool_share_id);
-		T::Currency::free_balance(currency_id, sender) > total
+		T::Currency::free_balance(currency_id, sender) >= total
 	}
 
 	pub fn get_balance(currency_id: CurrencyIdOf<T>, who: &T::AccountId) -> BalanceOf<T> {
@@ -502,6 +502,24 @@ impl<T: Config> TokenWrapperInterface<T::AccountId, T::AssetId, BalanceOf<T>, T:
 		Ok(())
 	}
 
+	fn contains_asset(pool_share_id: T::AssetId, asset_id: T::AssetId) -> bool {
+		<T::AssetRegistry as ShareTokenRegistry<
+			T::AssetId,
+			Vec<u8>,
+			T::Balance,
+			BoundedVec<u8, T::StringLimit>,
+			T::MaxAssetIdInPool,
+			DispatchError,
+		>>::contains_asset(pool_share_id, asset_id)
+	}
+
+	fn wrapping_fee(
+		amount: BalanceOf<T>,
+		into_pool_share_id: T::AssetId,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::get_wrapping_fee(amount, into_pool_share_id)
+	}
+
 	fn set_wrapping_fee(
 		into_pool_share_id: T::AssetId,
 		fee: BalanceOf<T>,
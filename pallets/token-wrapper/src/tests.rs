@@ -388,6 +388,59 @@ fn should_unwrap_when_liquidity_exists_for_selected_asset() {
 	})
 }
 
+#[test]
+fn should_wrap_entire_free_balance() {
+	new_test_ext().execute_with(|| {
+		let existential_balance: u32 = 1000;
+		let first_token_id = AssetRegistry::register_asset(
+			b"shib".to_vec().try_into().unwrap(),
+			AssetType::Token,
+			existential_balance.into(),
+		)
+		.unwrap();
+
+		let pool_share_id = AssetRegistry::register_asset(
+			b"meme".to_vec().try_into().unwrap(),
+			AssetType::PoolShare(vec![first_token_id].try_into().unwrap()),
+			existential_balance.into(),
+		)
+		.unwrap();
+
+		let recipient: u64 = 1;
+
+		let balance: i128 = 100000;
+
+		assert_ok!(Currencies::update_balance(
+			RuntimeOrigin::root(),
+			recipient,
+			first_token_id,
+			balance
+		));
+
+		assert_err!(
+			TokenWrapper::wrap(
+				RuntimeOrigin::signed(recipient),
+				first_token_id,
+				pool_share_id,
+				100001_u128,
+				recipient
+			),
+			crate::Error::<Test>::InsufficientBalance
+		);
+
+		// Wrapping exactly the free balance leaves nothing behind
+		assert_ok!(TokenWrapper::wrap(
+			RuntimeOrigin::signed(recipient),
+			first_token_id,
+			pool_share_id,
+			100000_u128,
+			recipient
+		));
+		assert_eq!(TokenWrapper::get_balance(first_token_id, &recipient), 0);
+		assert_eq!(TokenWrapper::get_balance(pool_share_id, &recipient), 100000);
+	})
+}
+
 #[test]
 fn should_not_wrap_invalid_amount() {
 	new_test_ext().execute_with(|| {
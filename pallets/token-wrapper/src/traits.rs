@@ -34,6 +34,13 @@ pub trait TokenWrapperInterface<AccountId, AssetId, Balance, Nonce> {
 		amount: Balance,
 		recipient: AccountId,
 	) -> Result<(), dispatch::DispatchError>;
+	/// Whether `asset_id` is one of the assets wrapped into `pool_share_id`
+	fn contains_asset(pool_share_id: AssetId, asset_id: AssetId) -> bool;
+	/// Fee charged on top of `amount` for wrapping it into `into_pool_share_id`
+	fn wrapping_fee(
+		amount: Balance,
+		into_pool_share_id: AssetId,
+	) -> Result<Balance, dispatch::DispatchError>;
 	fn add_asset_to_existing_pool(
 		name: &Vec<u8>,
 		asset_id: AssetId,
@@ -0,0 +1,29 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+use webb_primitives::webb_proposals::ResourceId;
+
+sp_api::decl_runtime_apis! {
+	pub trait VAnchorHandlerApi {
+		/// Get every anchor update proposal generated on-chain that is waiting to be
+		/// signed, as its tree ID, target resource ID, nonce and proposal bytes
+		fn get_pending_anchor_updates() -> Vec<(u32, ResourceId, u32, Vec<u8>)>;
+	}
+}
@@ -25,6 +25,7 @@
 //!
 //! * The creation of anchors from proposals
 //! * Updating existing anchors from proposals
+//! * Generating the anchor updates that linked anchors need after each deposit
 //!
 //! ## Interface
 //!
@@ -34,6 +35,25 @@
 //!   method requires the `origin` to be [T::BridgeOrigin].
 //! * `execute_vanchor_update_proposal`: Adds/Updates a vanchor from successfully voted on proposal.
 //!   This method requires the `origin` to be [T::BridgeOrigin].
+//! * `execute_pause_proposal`: Pauses deposits or all transactions of a vanchor from successfully
+//!   voted on proposal. This method requires the `origin` to be [T::BridgeOrigin].
+//! * `execute_unpause_proposal`: Resumes a paused vanchor from successfully voted on proposal. This
+//!   method requires the `origin` to be [T::BridgeOrigin].
+//!
+//! ### Dispatchable Functions
+//!
+//! * `acknowledge_anchor_update`: Marks a pending anchor update as signed, given a signature of the
+//!   bridge maintainer(s) over the proposal bytes generated on-chain.
+//!
+//! ### Outbound anchor updates
+//!
+//! When used as the `PostDepositHook` of its vanchor pallet, this module builds an
+//! `AnchorUpdateProposal` for every edge of a vanchor after each transaction, carrying the
+//! latest merkle root and leaf index of the tree. Proposals wait in `PendingAnchorUpdates`,
+//! keyed by the tree and the resource ID of the linked anchor, and a newer deposit replaces
+//! the one before it. Their bytes are served by the `VAnchorHandlerApi` runtime API. Signers sign
+//! exactly the bytes this chain produced and submit the signature through
+//! `acknowledge_anchor_update`, which emits it for relayers to pick up.
 //!
 //! ## Related Modules
 //!
@@ -43,17 +63,33 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 #[cfg(test)]
 pub mod mock_signature_bridge;
 #[cfg(test)]
 mod tests_signature_bridge;
+pub mod types;
 
-use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::EnsureOrigin};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	ensure,
+	traits::{EnsureOrigin, Get},
+	weights::Weight,
+};
 use frame_system::pallet_prelude::OriginFor;
-use pallet_vanchor::{BalanceOf as VAnchorBalanceOf, CurrencyIdOf as VAnchorCurrencyIdOf};
-use sp_std::convert::TryInto;
+use pallet_vanchor::{
+	BalanceOf as VAnchorBalanceOf, CurrencyIdOf as VAnchorCurrencyIdOf, PostDepositHook,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::{convert::TryInto, prelude::*};
+use types::AnchorUpdate;
 use webb_primitives::{
-	traits::vanchor::{VAnchorConfig, VAnchorInspector, VAnchorInterface},
+	traits::{
+		linkable_tree::LinkableTreeInspector,
+		signature_bridge::VerifyMaintainerSignature,
+		vanchor::{VAnchorConfig, VAnchorInspector, VAnchorInterface},
+	},
+	types::{amount::I256, vanchor::PauseStatus},
 	webb_proposals::{ResourceId, TargetSystem},
 };
 
@@ -66,9 +102,12 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use pallet_vanchor::VAnchorConfiguration;
 
+	pub type AnchorUpdateOf<T, I> =
+		AnchorUpdate<<T as pallet_mt::Config<I>>::Element, <T as pallet_mt::Config<I>>::LeafIndex>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-
+	#[pallet::storage_version(migrations::STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -83,6 +122,13 @@ pub mod pallet {
 		/// VAnchor Interface
 		type VAnchor: VAnchorInterface<VAnchorConfiguration<Self, I>>
 			+ VAnchorInspector<VAnchorConfiguration<Self, I>>;
+
+		/// Verifies the maintainer signatures of generated anchor updates
+		type MaintainerSignature: VerifyMaintainerSignature;
+
+		/// The maximum length of a maintainer signature over an anchor update
+		#[pallet::constant]
+		type MaxSignatureLength: Get<u32>;
 	}
 
 	/// The map of trees to their anchor metadata
@@ -91,6 +137,26 @@ pub mod pallet {
 	pub type AnchorList<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, ResourceId, T::TreeId, ValueQuery>;
 
+	/// The resource ID of each anchored tree, the reverse of `AnchorList`
+	#[pallet::storage]
+	#[pallet::getter(fn anchor_resource_id)]
+	pub type AnchorResourceIds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TreeId, ResourceId, OptionQuery>;
+
+	/// The anchor updates waiting to be signed, by tree and by the resource ID
+	/// of the linked anchor they target
+	#[pallet::storage]
+	#[pallet::getter(fn pending_anchor_updates)]
+	pub type PendingAnchorUpdates<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::TreeId,
+		Blake2_128Concat,
+		ResourceId,
+		AnchorUpdateOf<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -98,6 +164,20 @@ pub mod pallet {
 		AnchorEdgeAdded,
 		AnchorEdgeUpdated,
 		ResourceAnchored,
+		AnchorPaused,
+		AnchorUnpaused,
+		/// An anchor update for a linked anchor is waiting to be signed.
+		AnchorUpdateQueued {
+			tree_id: T::TreeId,
+			target_resource_id: ResourceId,
+			nonce: u32,
+		},
+		/// An anchor update was signed by the maintainer(s) and can be relayed.
+		AnchorUpdateSigned {
+			target_resource_id: ResourceId,
+			proposal_data: Vec<u8>,
+			signature: Vec<u8>,
+		},
 	}
 
 	#[pallet::error]
@@ -116,6 +196,14 @@ pub mod pallet {
 		InvalidNonce,
 		/// Invalid resource ID
 		InvalidResourceId,
+		/// No anchor update is pending for this tree and resource ID.
+		AnchorUpdateNotFound,
+		/// Signature verification failed.
+		InvalidSignature,
+		/// The latest leaf index of the tree does not fit in the nonce of an anchor update.
+		AnchorUpdateNonceOverflow,
+		/// The merkle root of the anchor update does not fit in an anchor update proposal.
+		InvalidMerkleRoot,
 	}
 
 	#[pallet::hooks]
@@ -207,13 +295,82 @@ pub mod pallet {
 
 			Self::set_min_withdraw_amount(min_withdraw_limit, nonce)
 		}
+
+		/// Execute pause proposal.
+		/// Halts deposits or all transactions of the vanchor of `r_id`, so that bridge governance
+		/// can stop every connected chain at once.
+		#[pallet::weight(195_000_000)]
+		#[pallet::call_index(5)]
+		pub fn execute_pause_proposal(
+			origin: OriginFor<T>,
+			r_id: ResourceId,
+			status: PauseStatus,
+			nonce: T::ProposalNonce,
+		) -> DispatchResultWithPostInfo {
+			T::BridgeOrigin::ensure_origin(origin)?;
+			let tree_id = Self::tree_id_of(r_id)?;
+			T::VAnchor::set_pause_status(tree_id, Some(status), nonce)?;
+			Self::deposit_event(Event::AnchorPaused);
+			Ok(().into())
+		}
+
+		/// Execute unpause proposal.
+		/// Resumes every transaction of the vanchor of `r_id`.
+		#[pallet::weight(195_000_000)]
+		#[pallet::call_index(6)]
+		pub fn execute_unpause_proposal(
+			origin: OriginFor<T>,
+			r_id: ResourceId,
+			nonce: T::ProposalNonce,
+		) -> DispatchResultWithPostInfo {
+			T::BridgeOrigin::ensure_origin(origin)?;
+			let tree_id = Self::tree_id_of(r_id)?;
+			T::VAnchor::set_pause_status(tree_id, None, nonce)?;
+			Self::deposit_event(Event::AnchorUnpaused);
+			Ok(().into())
+		}
+
+		/// Acknowledges the pending anchor update of `tree_id` for the anchor of
+		/// `target_resource_id` as signed.
+		///
+		/// `signature` must be from the bridge maintainer(s) over the proposal bytes
+		/// generated on-chain, and `nonce` must be the nonce of the pending update so
+		/// that a signature over a replaced update is rejected.
+		#[pallet::weight(195_000_000)]
+		#[pallet::call_index(7)]
+		pub fn acknowledge_anchor_update(
+			origin: OriginFor<T>,
+			tree_id: T::TreeId,
+			target_resource_id: ResourceId,
+			nonce: u32,
+			signature: BoundedVec<u8, T::MaxSignatureLength>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let update = PendingAnchorUpdates::<T, I>::get(tree_id, target_resource_id)
+				.ok_or(Error::<T, I>::AnchorUpdateNotFound)?;
+			ensure!(update.nonce == nonce, Error::<T, I>::InvalidNonce);
+			let proposal_data = update
+				.to_proposal_bytes(target_resource_id)
+				.ok_or(Error::<T, I>::InvalidMerkleRoot)?;
+			ensure!(
+				T::MaintainerSignature::verify_maintainer_signature(&proposal_data, &signature),
+				Error::<T, I>::InvalidSignature
+			);
+			PendingAnchorUpdates::<T, I>::remove(tree_id, target_resource_id);
+			Self::deposit_event(Event::AnchorUpdateSigned {
+				target_resource_id,
+				proposal_data,
+				signature: signature.into_inner(),
+			});
+			Ok(Pays::No.into())
+		}
 	}
 }
 
 impl<T: Config<I>, I: 'static> VAnchorConfig for Pallet<T, I> {
 	type AccountId = T::AccountId;
 	type Balance = VAnchorBalanceOf<T, I>;
-	type Amount = i128;
+	type Amount = I256;
 	type ChainId = T::ChainId;
 	type CurrencyId = VAnchorCurrencyIdOf<T, I>;
 	type Element = T::Element;
@@ -223,9 +380,58 @@ impl<T: Config<I>, I: 'static> VAnchorConfig for Pallet<T, I> {
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	fn tree_id_of(r_id: ResourceId) -> Result<T::TreeId, DispatchError> {
+		match r_id.target_system() {
+			TargetSystem::Substrate(system) => Ok(system.tree_id.into()),
+			_ => Err(Error::<T, I>::InvalidResourceId.into()),
+		}
+	}
+
+	/// Returns every anchor update waiting to be signed as its tree ID, target resource ID and
+	/// nonce, which `acknowledge_anchor_update` takes, along with the bytes of its proposal.
+	pub fn pending_anchor_update_proposals() -> Vec<(T::TreeId, ResourceId, u32, Vec<u8>)> {
+		PendingAnchorUpdates::<T, I>::iter()
+			.filter_map(|(tree_id, target_resource_id, update)| {
+				let proposal_data = update.to_proposal_bytes(target_resource_id)?;
+				Some((tree_id, target_resource_id, update.nonce, proposal_data))
+			})
+			.collect()
+	}
+
+	/// Replaces the pending anchor updates of `tree_id` with ones carrying its
+	/// current root, for every linked anchor.
+	fn queue_anchor_updates(tree_id: T::TreeId) -> DispatchResult {
+		let src_resource_id = match AnchorResourceIds::<T, I>::get(tree_id) {
+			Some(r_id) => r_id,
+			// the tree is not bridged through this handler
+			None => return Ok(()),
+		};
+		let merkle_root = pallet_linkable_tree::Pallet::<T, I>::get_root(tree_id)?;
+		let latest_leaf_index =
+			pallet_mt::Pallet::<T, I>::next_leaf_index(tree_id).saturating_sub(1u32.into());
+		// linked anchors expect the leaf index as the nonce of the update
+		let nonce: u32 = latest_leaf_index
+			.try_into()
+			.map_err(|_| Error::<T, I>::AnchorUpdateNonceOverflow)?;
+		let max_edges = pallet_linkable_tree::Pallet::<T, I>::max_edges(tree_id);
+		for edge in pallet_linkable_tree::EdgeList::<T, I>::iter_prefix_values(tree_id)
+			.take(max_edges as usize)
+		{
+			let target_resource_id = edge.src_resource_id;
+			PendingAnchorUpdates::<T, I>::insert(
+				tree_id,
+				target_resource_id,
+				AnchorUpdate { src_resource_id, merkle_root, latest_leaf_index, nonce },
+			);
+			Self::deposit_event(Event::AnchorUpdateQueued { tree_id, target_resource_id, nonce });
+		}
+		Ok(())
+	}
+
 	fn set_resource(r_id: ResourceId, tree_id: T::TreeId) -> DispatchResultWithPostInfo {
 		ensure!(!AnchorList::<T, I>::contains_key(r_id), Error::<T, I>::ResourceIsAlreadyAnchored);
 		AnchorList::<T, I>::insert(r_id, tree_id);
+		AnchorResourceIds::<T, I>::insert(tree_id, r_id);
 		Self::deposit_event(Event::ResourceAnchored);
 		Ok(().into())
 	}
@@ -292,3 +498,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(().into())
 	}
 }
+
+impl<T: Config<I>, I: 'static> PostDepositHook<T, I> for Pallet<T, I> {
+	fn post_deposit(_: T::AccountId, id: T::TreeId, _: T::Element) -> DispatchResult {
+		Self::queue_anchor_updates(id)
+	}
+
+	fn post_deposit_weight(max_edges: u32) -> Weight {
+		// AnchorResourceIds, the root, NextLeafIndex and MaxEdges, then an edge read
+		// and a pending update written per edge
+		let edges = max_edges as u64;
+		T::DbWeight::get().reads_writes(4u64.saturating_add(edges), edges)
+	}
+}
@@ -0,0 +1,30 @@
+//! Storage migrations of the VAnchor handler pallet
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+/// The storage version set by the latest migration of the pallet.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Seeds `AnchorResourceIds` from the resources anchored before it was
+/// introduced.
+pub struct SeedAnchorResourceIds<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for SeedAnchorResourceIds<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T, I>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1)
+		}
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+
+		for (r_id, tree_id) in AnchorList::<T, I>::iter() {
+			AnchorResourceIds::<T, I>::insert(tree_id, r_id);
+			reads += 1;
+			writes += 1;
+		}
+
+		STORAGE_VERSION.put::<Pallet<T, I>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
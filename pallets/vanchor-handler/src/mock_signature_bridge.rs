@@ -14,11 +14,17 @@ use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{AccountIdConversion, BlakeTwo256, ConstU32, IdentityLookup},
+	traits::{
+		AccountIdConversion, BlakeTwo256, ConstBool, ConstU128, ConstU32, ConstU64, IdentityLookup,
+	},
 };
 use sp_std::convert::{TryFrom, TryInto};
 use webb_primitives::{
-	field_ops::ArkworksIntoFieldBn254, verifying::ArkworksVerifierBn254, webb_proposals::ResourceId,
+	field_ops::ArkworksIntoFieldBn254,
+	proposals::TypedProposal,
+	signature_bridge::TypedProposalDecoder,
+	verifying::ArkworksVerifierBn254,
+	webb_proposals::{ProposalHeader, ResourceId},
 };
 pub use webb_primitives::{hashing::ethereum::Keccak256HasherBn254, ElementTrait, InstanceHasher};
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -317,6 +323,24 @@ impl Contains<RuntimeCall> for ExecuteProposalFilter {
 	}
 }
 
+pub struct MockProposalDecoder;
+impl TypedProposalDecoder<RuntimeCall> for MockProposalDecoder {
+	fn decode_proposal(header: &ProposalHeader, proposal_data: &[u8]) -> Option<RuntimeCall> {
+		match TypedProposal::decode(header, proposal_data)? {
+			TypedProposal::AnchorUpdate { merkle_root, src_resource_id } =>
+				Some(RuntimeCall::VAnchorHandler(
+					pallet_vanchor_handler::Call::execute_vanchor_update_proposal {
+						r_id: header.resource_id(),
+						merkle_root: Element::from_bytes(&merkle_root),
+						src_resource_id,
+						nonce: header.nonce().to_u32(),
+					},
+				)),
+			_ => None,
+		}
+	}
+}
+
 pub type ProposalNonce = u32;
 pub type MaintainerNonce = u32;
 
@@ -329,10 +353,17 @@ impl pallet_signature_bridge::Config<BridgeInstance> for Test {
 	type ChainType = ChainType;
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalLifetime = ProposalLifetime;
+	type ExecutedProposalHistorySize = ConstU32<100>;
+	type ProposalDelay = ConstU64<0>;
 	type MaxStringLength = ConstU32<1000>;
+	type MaxMaintainers = ConstU32<10>;
+	type MaxProposalsPerBatch = ConstU32<32>;
 	type ProposalNonce = ProposalNonce;
 	type SetResourceProposalFilter = SetResourceProposalFilter;
 	type ExecuteProposalFilter = ExecuteProposalFilter;
+	type ProposalResourceMatcher = frame_support::traits::Everything;
+	type SensitiveProposalFilter = frame_support::traits::Nothing;
+	type ProposalDecoder = MockProposalDecoder;
 	type MaintainerNonce = MaintainerNonce;
 	type SignatureVerifier = webb_primitives::signing::SignatureVerifier;
 	type WeightInfo = ();
@@ -358,10 +389,21 @@ impl pallet_vanchor::Config for Test {
 	type MaxExtAmount = MaxExtAmount;
 	type MaxCurrencyId = MaxCurrencyId;
 	type TokenWrapper = TokenWrapper;
-	type PostDepositHook = ();
+	type PostDepositHook = VAnchorHandler;
 	type ProposalNonce = u32;
 	type VAnchorVerifier = VAnchorVerifier;
 	type KeyStorage = KeyStorage;
+	type BlockAuthor = ();
+	type UnsignedPriority = ConstU64<1_000>;
+	type MaxUnsignedFeePriority = ConstU64<100>;
+	type MinUnsignedFee = ConstU128<1>;
+	type MaxUnsignedTransactsPerBlock = ConstU32<2>;
+	type MaxEncryptedOutputLength = ConstU32<1_024>;
+	type RequireEncryptedOutputEnvelope = ConstBool<false>;
+	type WithdrawalWindowLength = ConstU64<10>;
+	type WithdrawalReleaseDelay = ConstU64<10>;
+	type TransactFilter = ();
+	type Mixer = ();
 	type WeightInfo = ();
 }
 
@@ -369,6 +411,8 @@ impl pallet_vanchor_handler::Config for Test {
 	type VAnchor = VAnchor;
 	type BridgeOrigin = pallet_signature_bridge::EnsureBridge<Test, BridgeInstance>;
 	type RuntimeEvent = RuntimeEvent;
+	type MaintainerSignature = SignatureBridge;
+	type MaxSignatureLength = ConstU32<1000>;
 }
 
 impl pallet_key_storage::Config for Test {
@@ -1,11 +1,21 @@
 use crate::{
+	migrations::{SeedAnchorResourceIds, STORAGE_VERSION},
 	mock_signature_bridge::{new_test_ext_initialized, *},
-	AnchorList,
+	types::AnchorUpdate,
+	AnchorList, AnchorResourceIds,
+};
+use pallet_vanchor::PostDepositHook;
+use webb_primitives::{
+	traits::linkable_tree::{LinkableTreeInspector, LinkableTreeInterface},
+	types::vanchor::PauseStatus,
 };
 
 use arkworks_setups::{common::setup_params, Curve};
 use codec::Encode;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use hex_literal::hex;
 use pallet_linkable_tree::types::EdgeMetadata;
 use sp_core::{
@@ -24,6 +34,8 @@ const TEST_TREE_DEPTH: u8 = 32;
 const ANCHOR_CREATE_FUNCTION_SIG: FunctionSignature = FunctionSignature::new(0u32.to_be_bytes());
 const ANCHOR_UPDATE_FUNCTION_SIG: FunctionSignature = FunctionSignature::new(1u32.to_be_bytes());
 const SET_RESOURCE_FUNCTION_SIG: FunctionSignature = FunctionSignature::new(2u32.to_be_bytes());
+const PAUSE_FUNCTION_SIG: FunctionSignature = FunctionSignature::new(5u32.to_be_bytes());
+const UNPAUSE_FUNCTION_SIG: FunctionSignature = FunctionSignature::new(6u32.to_be_bytes());
 
 fn make_set_resource_proposal(
 	header: webb_proposals::ProposalHeader,
@@ -61,6 +73,7 @@ fn mock_vanchor_creation_using_pallet_call(resource_id: &ResourceId) {
 	assert_ok!(VAnchor::create(RuntimeOrigin::root(), TEST_MAX_EDGES, TEST_TREE_DEPTH, 0));
 	// hack: insert an entry in AnchorsList with tree-id=0
 	AnchorList::<Test>::insert(resource_id, 0);
+	AnchorResourceIds::<Test>::insert(0, resource_id);
 	// make sure Tree(with id=0) exists in `pallet_mt` storage
 	assert!(<pallet_mt::Trees<Test>>::contains_key(0));
 	// check that anchor has stored `TEST_MAX_EDGES` correctly
@@ -611,5 +624,290 @@ fn should_add_resource_sig_succeed_using_webb_proposals() {
 		event_exists(crate::Event::ResourceAnchored);
 		// edge count should be 1
 		assert_eq!(1, AnchorList::<Test>::iter_keys().count());
+		assert_eq!(VAnchorHandler::anchor_resource_id(5), Some(resource));
+	})
+}
+
+// Test
+// 1. Create an anchor using `pallet-vanchor` intrinsic call
+// 2. Pause the anchor using `pallet-vanchor-handler` proposal through
+// `pallet-signature-bridge`
+// 3. Unpause the anchor the same way
+#[test]
+fn should_pause_and_unpause_vanchor_with_sig_succeed() {
+	let src_id = TypedChainId::Substrate(1);
+	let target_id = TypedChainId::Substrate(5);
+	let target_system =
+		TargetSystem::Substrate(SubstrateTargetSystem { pallet_index: 11, tree_id: 0 });
+	let r_id: ResourceId = ResourceId::new(target_system, target_id);
+	let public_uncompressed = get_public_uncompressed_key();
+	let pair = get_edsca_account();
+
+	new_test_ext_initialized(
+		src_id.chain_id(),
+		r_id,
+		b"VAnchorHandler.execute_pause_proposal".to_vec(),
+	)
+	.execute_with(|| {
+		let curve = Curve::Bn254;
+		let params = setup_params::<ark_bn254::Fr>(curve, 5, 3);
+		let _ = HasherPallet::force_set_parameters(
+			RuntimeOrigin::root(),
+			params.to_bytes().try_into().unwrap(),
+		);
+
+		mock_vanchor_creation_using_pallet_call(&r_id);
+		// set the maintainer
+		assert_ok!(SignatureBridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			public_uncompressed.to_vec().try_into().unwrap()
+		));
+
+		let nonce = 2u32;
+		let pause_call = RuntimeCall::VAnchorHandler(crate::Call::execute_pause_proposal {
+			r_id,
+			status: PauseStatus::All,
+			nonce,
+		});
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			PAUSE_FUNCTION_SIG,
+			nonce.to_be_bytes(),
+			pause_call.encode(),
+		);
+		let sig: Signature = pair.sign_prehashed(&keccak_256(&prop_data));
+		assert_ok!(SignatureBridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id.chain_id(),
+			prop_data.try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		event_exists(crate::Event::AnchorPaused);
+		assert_eq!(pallet_vanchor::PausedVAnchors::<Test>::get(0), Some(PauseStatus::All));
+
+		let nonce = 3u32;
+		let unpause_call =
+			RuntimeCall::VAnchorHandler(crate::Call::execute_unpause_proposal { r_id, nonce });
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			UNPAUSE_FUNCTION_SIG,
+			nonce.to_be_bytes(),
+			unpause_call.encode(),
+		);
+		let sig: Signature = pair.sign_prehashed(&keccak_256(&prop_data));
+		assert_ok!(SignatureBridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id.chain_id(),
+			prop_data.try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		event_exists(crate::Event::AnchorUnpaused);
+		assert_eq!(pallet_vanchor::PausedVAnchors::<Test>::get(0), None);
 	})
 }
+
+#[test]
+fn should_add_vanchor_edge_with_typed_anchor_update_proposal() {
+	let src_id = TypedChainId::Substrate(1);
+	let target_id = TypedChainId::Substrate(5);
+	let target_system =
+		TargetSystem::Substrate(SubstrateTargetSystem { pallet_index: 11, tree_id: 0 });
+	let r_id: ResourceId = ResourceId::new(target_system, target_id);
+	let public_uncompressed = get_public_uncompressed_key();
+	let pair = get_edsca_account();
+
+	new_test_ext_initialized(
+		src_id.chain_id(),
+		r_id,
+		b"VAnchorHandler.execute_vanchor_update_proposal".to_vec(),
+	)
+	.execute_with(|| {
+		let curve = Curve::Bn254;
+		let params = setup_params::<ark_bn254::Fr>(curve, 5, 3);
+		let _ = HasherPallet::force_set_parameters(
+			RuntimeOrigin::root(),
+			params.to_bytes().try_into().unwrap(),
+		);
+
+		mock_vanchor_creation_using_pallet_call(&r_id);
+
+		let root = Element::from_bytes(&[1; 32]);
+		let latest_leaf_index = 5;
+		let src_resource_id = ResourceId::new(target_system, src_id);
+		// the body of an `AnchorUpdateProposal` is the merkle root followed by the source
+		// resource ID, as signed for EVM chains
+		let mut body = root.to_bytes().to_vec();
+		body.extend_from_slice(&src_resource_id.0);
+		let prop_data = make_proposal_data(
+			r_id.encode(),
+			webb_primitives::proposals::ANCHOR_UPDATE_FUNCTION_SIG,
+			latest_leaf_index.to_be_bytes(),
+			body,
+		);
+		let msg = keccak_256(&prop_data);
+		let sig: Signature = pair.sign_prehashed(&msg);
+		// set the maintainer
+		assert_ok!(SignatureBridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			public_uncompressed.to_vec().try_into().unwrap()
+		));
+
+		assert_ok!(SignatureBridge::execute_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id.chain_id(),
+			prop_data.try_into().unwrap(),
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		event_exists(crate::Event::AnchorEdgeAdded);
+		assert_eq!(
+			EdgeMetadata {
+				src_chain_id: src_id.chain_id(),
+				root,
+				latest_leaf_index,
+				src_resource_id
+			},
+			<pallet_linkable_tree::EdgeList<Test>>::get(0, src_id.chain_id())
+		);
+	})
+}
+
+// Test
+// 1. Create an anchor linked to another chain's anchor
+// 2. Insert a leaf and run the post deposit hook of the vanchor
+// 3. Sign the generated anchor update and acknowledge it
+#[test]
+fn should_generate_and_acknowledge_outbound_anchor_update() {
+	let src_id = TypedChainId::Substrate(1);
+	let this_chain_id = TypedChainId::Substrate(5);
+	let target_system =
+		TargetSystem::Substrate(SubstrateTargetSystem { pallet_index: 11, tree_id: 0 });
+	let r_id: ResourceId = ResourceId::new(target_system, this_chain_id);
+	let linked_resource_id = ResourceId::new(target_system, src_id);
+	let public_uncompressed = get_public_uncompressed_key();
+	let pair = get_edsca_account();
+
+	new_test_ext_initialized(
+		src_id.chain_id(),
+		r_id,
+		b"VAnchorHandler.execute_vanchor_update_proposal".to_vec(),
+	)
+	.execute_with(|| {
+		let curve = Curve::Bn254;
+		let params = setup_params::<ark_bn254::Fr>(curve, 5, 3);
+		let _ = HasherPallet::force_set_parameters(
+			RuntimeOrigin::root(),
+			params.to_bytes().try_into().unwrap(),
+		);
+
+		mock_vanchor_creation_using_pallet_call(&r_id);
+		assert_ok!(<LinkableTree as LinkableTreeInterface<_>>::add_edge(
+			0,
+			src_id.chain_id(),
+			Element::from_bytes(&[1; 32]),
+			0,
+			linked_resource_id,
+		));
+
+		let leaf = Element::from_bytes(&[2; 32]);
+		assert_ok!(<LinkableTree as LinkableTreeInterface<_>>::insert_in_order(0, leaf));
+		assert_ok!(<VAnchorHandler as PostDepositHook<Test, ()>>::post_deposit(RELAYER_A, 0, leaf));
+
+		let root = <LinkableTree as LinkableTreeInspector<_>>::get_root(0).unwrap();
+		let update = AnchorUpdate {
+			src_resource_id: r_id,
+			merkle_root: root,
+			latest_leaf_index: 0,
+			nonce: 0,
+		};
+		assert_eq!(VAnchorHandler::pending_anchor_updates(0, linked_resource_id), Some(update));
+		event_exists(crate::Event::AnchorUpdateQueued {
+			tree_id: 0,
+			target_resource_id: linked_resource_id,
+			nonce: 0,
+		});
+
+		// the chain produces the exact bytes linked anchors execute
+		let mut body = root.to_bytes().to_vec();
+		body.extend_from_slice(&r_id.0);
+		let prop_data = make_proposal_data(
+			linked_resource_id.encode(),
+			webb_primitives::proposals::ANCHOR_UPDATE_FUNCTION_SIG,
+			0u32.to_be_bytes(),
+			body,
+		);
+		assert_eq!(
+			VAnchorHandler::pending_anchor_update_proposals(),
+			vec![(0, linked_resource_id, 0, prop_data.clone())]
+		);
+
+		assert_ok!(SignatureBridge::force_set_maintainer(
+			RuntimeOrigin::root(),
+			1u32,
+			public_uncompressed.to_vec().try_into().unwrap()
+		));
+		let sig: Signature = pair.sign_prehashed(&keccak_256(&prop_data));
+
+		// a signature over another proposal is rejected
+		let other_sig: Signature = pair.sign_prehashed(&keccak_256(b"another proposal"));
+		assert_err!(
+			VAnchorHandler::acknowledge_anchor_update(
+				RuntimeOrigin::signed(RELAYER_A),
+				0,
+				linked_resource_id,
+				0,
+				other_sig.0.to_vec().try_into().unwrap(),
+			),
+			crate::Error::<Test>::InvalidSignature
+		);
+		// so is an acknowledgement for a replaced update
+		assert_err!(
+			VAnchorHandler::acknowledge_anchor_update(
+				RuntimeOrigin::signed(RELAYER_A),
+				0,
+				linked_resource_id,
+				1,
+				sig.0.to_vec().try_into().unwrap(),
+			),
+			crate::Error::<Test>::InvalidNonce
+		);
+
+		assert_ok!(VAnchorHandler::acknowledge_anchor_update(
+			RuntimeOrigin::signed(RELAYER_A),
+			0,
+			linked_resource_id,
+			0,
+			sig.0.to_vec().try_into().unwrap(),
+		));
+		event_exists(crate::Event::AnchorUpdateSigned {
+			target_resource_id: linked_resource_id,
+			proposal_data: prop_data,
+			signature: sig.0.to_vec(),
+		});
+		assert_eq!(VAnchorHandler::pending_anchor_updates(0, linked_resource_id), None);
+		assert!(VAnchorHandler::pending_anchor_update_proposals().is_empty());
+	})
+}
+
+#[test]
+fn seed_anchor_resource_ids_migration_should_map_anchored_trees() {
+	let target_system =
+		TargetSystem::Substrate(SubstrateTargetSystem { pallet_index: 11, tree_id: 3 });
+	let r_id = ResourceId::new(target_system, TypedChainId::Substrate(5));
+
+	new_test_ext().execute_with(|| {
+		// Anchored before the reverse map was introduced
+		AnchorList::<Test>::insert(r_id, 3);
+		StorageVersion::new(0).put::<VAnchorHandler>();
+		SeedAnchorResourceIds::<Test>::on_runtime_upgrade();
+
+		assert_eq!(VAnchorHandler::anchor_resource_id(3), Some(r_id));
+		assert_eq!(VAnchorHandler::on_chain_storage_version(), STORAGE_VERSION);
+
+		// The migration only runs once
+		AnchorResourceIds::<Test>::remove(3);
+		SeedAnchorResourceIds::<Test>::on_runtime_upgrade();
+		assert_eq!(VAnchorHandler::anchor_resource_id(3), None);
+	});
+}
@@ -0,0 +1,41 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use webb_primitives::{
+	proposals::ANCHOR_UPDATE_FUNCTION_SIG,
+	webb_proposals::{evm::AnchorUpdateProposal, Nonce, ProposalHeader, ResourceId},
+	ElementTrait,
+};
+
+/// An anchor update generated by this chain for one of the edges of a vanchor,
+/// waiting to be signed by the bridge maintainer(s).
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AnchorUpdate<Element, LeafIndex> {
+	/// Resource ID of the vanchor on this chain
+	pub src_resource_id: ResourceId,
+	/// Root of the vanchor's merkle tree
+	pub merkle_root: Element,
+	/// Index of the last leaf inserted into the tree
+	pub latest_leaf_index: LeafIndex,
+	/// Nonce of the proposal, which linked anchors check against the leaf index
+	pub nonce: u32,
+}
+
+impl<Element: ElementTrait, LeafIndex> AnchorUpdate<Element, LeafIndex> {
+	/// Returns the bytes of the `AnchorUpdateProposal` for the anchor of `target_resource_id`,
+	/// or `None` if the merkle root is not 32 bytes long.
+	pub fn to_proposal_bytes(&self, target_resource_id: ResourceId) -> Option<Vec<u8>> {
+		let header = ProposalHeader::new(
+			target_resource_id,
+			ANCHOR_UPDATE_FUNCTION_SIG,
+			Nonce::new(self.nonce),
+		);
+		let merkle_root: [u8; 32] = self.merkle_root.to_bytes().try_into().ok()?;
+		Some(
+			AnchorUpdateProposal::new(header, merkle_root, self.src_resource_id)
+				.into_bytes()
+				.to_vec(),
+		)
+	}
+}
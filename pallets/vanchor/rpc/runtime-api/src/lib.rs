@@ -0,0 +1,40 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+use webb_primitives::types::vanchor::{AssetSolvency, TreeFlows};
+
+sp_api::decl_runtime_apis! {
+	pub trait VAnchorApi<Balance, CurrencyId>
+	where
+		Balance: Codec,
+		CurrencyId: Codec,
+	{
+		/// Get the amount that can still be withdrawn from a tree in the current
+		/// window, or `None` if its withdrawals are not limited
+		fn remaining_withdrawal_capacity(tree_id: u32) -> Option<Balance>;
+		/// Get the cumulative deposits, withdrawals and fees of a tree, and the
+		/// balance locked in it
+		fn tree_flows(tree_id: u32) -> TreeFlows<Balance>;
+		/// Compare the holdings of the pallet account with the balances locked in
+		/// the trees of each asset
+		fn solvency() -> Vec<AssetSolvency<CurrencyId, Balance>>;
+	}
+}
@@ -132,6 +132,7 @@ benchmarks_instance_pallet! {
 	}
 
 	transact {
+		let l in 0 .. 2 * <T as crate::Config<I>>::MaxEncryptedOutputLength::get();
 		let pk_2_2_bytes =  setup_env::<T,I>();
 
 		let deposit_size: u32 = 50_000_000;
@@ -145,7 +146,7 @@ benchmarks_instance_pallet! {
 		let transactor : T::AccountId = account("", 0, SEED);
 		let recipient : T::AccountId = account("", 1, SEED);
 		let relayer: T::AccountId = account("", 4, SEED);
-		let ext_amount: u32 = 10;
+		let ext_amount: u128 = 10;
 		let fee: u32 = 0;
 
 		<<T as pallet_mt::Config<I>>::Currency as Currency<T::AccountId>>::make_free_balance_be(&transactor.clone(), 100_000_000u32.into());
@@ -165,9 +166,12 @@ benchmarks_instance_pallet! {
 		// transaction
 		let out_utxos = setup_utxos(out_chain_ids, out_amounts, Some(in_indices));
 
-		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
-		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>::new(
+		// Pad the mock outputs to measure the cost of the encrypted output length
+		let mut output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
+		let mut output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
+		output1.resize((l / 2) as usize, 0u8);
+		output2.resize((l / 2) as usize, 0u8);
+		let ext_data = ExtData::<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>::new(
 			recipient.into(),
 			relayer.into(),
 			ext_amount.into(),
@@ -217,6 +221,7 @@ benchmarks_instance_pallet! {
 	}
 
 	register_and_transact {
+		let l in 0 .. 2 * <T as crate::Config<I>>::MaxEncryptedOutputLength::get();
 		let pk_2_2_bytes =  setup_env::<T,I>();
 		let deposit_size: u32 = 50_000_000;
 		let asset_id = <<T as crate::Config<I>>::NativeCurrencyId as Get<crate::CurrencyIdOf<T, I>>>::get();
@@ -229,7 +234,7 @@ benchmarks_instance_pallet! {
 		let transactor : T::AccountId = account("", 0, SEED);
 		let recipient : T::AccountId = account("", 1, SEED);
 		let relayer: T::AccountId = account("", 4, SEED);
-		let ext_amount: u32 = 10;
+		let ext_amount: u128 = 10;
 		let fee: u32 = 0;
 
 		<<T as pallet_mt::Config<I>>::Currency as Currency<T::AccountId>>::make_free_balance_be(&transactor.clone(), 100_000_000u32.into());
@@ -249,9 +254,12 @@ benchmarks_instance_pallet! {
 		// transaction
 		let out_utxos = setup_utxos(out_chain_ids, out_amounts, Some(in_indices));
 
-		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
-		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>::new(
+		// Pad the mock outputs to measure the cost of the encrypted output length
+		let mut output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
+		let mut output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
+		output1.resize((l / 2) as usize, 0u8);
+		output2.resize((l / 2) as usize, 0u8);
+		let ext_data = ExtData::<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>::new(
 			recipient.into(),
 			relayer.into(),
 			ext_amount.into(),
@@ -312,5 +320,61 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::MinWithdrawAmountChanged{ min_withdraw_amount : 1_u32.into() }.into())
 	}
 
+	set_pause_status {
+		let asset_id = <<T as crate::Config<I>>::NativeCurrencyId as Get<crate::CurrencyIdOf<T, I>>>::get();
+		let depth = <T as pallet_mt::Config<I>>::MaxTreeDepth::get();
+		let tree_id = <VAnchor<T, I> as VAnchorInterface<VAnchorConfiguration<T, I>>>::create(None, depth, 1u32, asset_id, 1u32.into())?;
+	}: _(RawOrigin::Root, tree_id, Some(PauseStatus::All), 101u32.into())
+	verify {
+		assert_last_event::<T, I>(Event::PauseStatusChanged{ tree_id, status: Some(PauseStatus::All) }.into())
+	}
+
+	set_withdrawal_limit {
+		let asset_id = <<T as crate::Config<I>>::NativeCurrencyId as Get<crate::CurrencyIdOf<T, I>>>::get();
+		let depth = <T as pallet_mt::Config<I>>::MaxTreeDepth::get();
+		let tree_id = <VAnchor<T, I> as VAnchorInterface<VAnchorConfiguration<T, I>>>::create(None, depth, 1u32, asset_id, 1u32.into())?;
+	}: _(RawOrigin::Root, tree_id, Some(100u32.into()))
+	verify {
+		assert_last_event::<T, I>(Event::WithdrawalLimitChanged{ tree_id, limit: Some(100u32.into()) }.into())
+	}
+
+	release_withdrawal {
+		let asset_id = <<T as crate::Config<I>>::NativeCurrencyId as Get<crate::CurrencyIdOf<T, I>>>::get();
+		let depth = <T as pallet_mt::Config<I>>::MaxTreeDepth::get();
+		let tree_id = <VAnchor<T, I> as VAnchorInterface<VAnchorConfiguration<T, I>>>::create(None, depth, 1u32, asset_id, 1u32.into())?;
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("", 1, SEED);
+		<<T as pallet_mt::Config<I>>::Currency as Currency<T::AccountId>>::make_free_balance_be(&VAnchor::<T, I>::account_id(), 100_000_000u32.into());
+		PendingWithdrawals::<T, I>::insert(0, PendingWithdrawal {
+			tree_id,
+			recipient,
+			token: asset_id,
+			amount: 10u32.into(),
+			release_at: 0u32.into(),
+		});
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_last_event::<T, I>(Event::WithdrawalReleased{ withdrawal_id: 0 }.into())
+	}
+
+	cancel_withdrawal {
+		let asset_id = <<T as crate::Config<I>>::NativeCurrencyId as Get<crate::CurrencyIdOf<T, I>>>::get();
+		let depth = <T as pallet_mt::Config<I>>::MaxTreeDepth::get();
+		let tree_id = <VAnchor<T, I> as VAnchorInterface<VAnchorConfiguration<T, I>>>::create(None, depth, 1u32, asset_id, 1u32.into())?;
+		let recipient: T::AccountId = account("", 1, SEED);
+		let refund_to: T::AccountId = account("", 2, SEED);
+		<<T as pallet_mt::Config<I>>::Currency as Currency<T::AccountId>>::make_free_balance_be(&VAnchor::<T, I>::account_id(), 100_000_000u32.into());
+		PendingWithdrawals::<T, I>::insert(0, PendingWithdrawal {
+			tree_id,
+			recipient,
+			token: asset_id,
+			amount: 10u32.into(),
+			release_at: 0u32.into(),
+		});
+	}: _(RawOrigin::Root, 0, refund_to.clone())
+	verify {
+		assert_last_event::<T, I>(Event::WithdrawalCancelled{ withdrawal_id: 0, refund_to }.into())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
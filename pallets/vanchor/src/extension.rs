@@ -0,0 +1,138 @@
+// This file is part of Webb.
+
+// Copyright (C) 2021-2023 Webb Technologies Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction pool validation of VAnchor transactions.
+
+use super::*;
+use frame_support::{
+	dispatch::DispatchInfo,
+	pallet_prelude::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	traits::{IsSubType, PalletInfoAccess},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::SignedExtension, ModuleError};
+use sp_std::{fmt, marker::PhantomData};
+
+/// Signed extension that pre-checks VAnchor `transact` calls when they enter the
+/// transaction pool.
+///
+/// Roots, nullifiers, the ext data hash and the amount limits are checked against
+/// the current state so that invalid transactions are dropped before they are
+/// included in a block. Each input nullifier is provided as a tag, which makes the
+/// pool reject a second transaction spending the same note. The zero-knowledge
+/// proof itself is only verified at dispatch.
+///
+/// A rejection by this pallet is reported as `InvalidTransaction::Custom(n)`, where
+/// `n` is the index of the [`Error`] variant. A custom validity code is a single
+/// byte and cannot also hold the pallet index, so errors of other pallets, such as
+/// an unknown root in the linkable tree, are reported as `InvalidTransaction::Call`.
+///
+/// The extension only checks calls of the instance `I` it is built for; a runtime
+/// with several vanchor instances needs one extension per instance.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct CheckVAnchorTransact<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T, I> CheckVAnchorTransact<T, I> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, I> Default for CheckVAnchorTransact<T, I> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, I> fmt::Debug for CheckVAnchorTransact<T, I> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckVAnchorTransact")
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Validates a `transact` call for the transaction pool, tagging it by the
+	/// nullifiers it spends.
+	pub fn validate_transact_unverified(
+		id: T::TreeId,
+		proof_data: &ProofData<T::Element>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> TransactionValidity {
+		Self::validate_transact(id, proof_data, ext_data).map_err(|e| match e {
+			DispatchError::Module(ModuleError { index, error, .. })
+				if index as usize == <Pallet<T, I> as PalletInfoAccess>::index() =>
+				InvalidTransaction::Custom(error[0]),
+			_ => InvalidTransaction::Call,
+		})?;
+
+		let mut valid_tx = ValidTransaction::with_tag_prefix("VAnchorNullifier");
+		for nullifier in &proof_data.input_nullifiers {
+			valid_tx = valid_tx.and_provides((
+				<Pallet<T, I> as PalletInfoAccess>::index() as u32,
+				id,
+				nullifier,
+			));
+		}
+		valid_tx.build()
+	}
+}
+
+impl<T, I> SignedExtension for CheckVAnchorTransact<T, I>
+where
+	T: Config<I> + Send + Sync,
+	I: 'static + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T, I>>,
+{
+	const IDENTIFIER: &'static str = "CheckVAnchorTransact";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfo,
+		_len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(Call::transact { id, proof_data, ext_data }) |
+			Some(Call::register_and_transact { id, proof_data, ext_data, .. }) =>
+				Pallet::<T, I>::validate_transact_unverified(*id, proof_data, ext_data),
+			_ => Ok(Default::default()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfo,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		// All checks are repeated by `transact` itself during dispatch.
+		Ok(())
+	}
+}
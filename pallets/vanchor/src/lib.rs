@@ -35,7 +35,39 @@
 //!
 //! * `create`: Creates an vanchor and inserts an element into the on-chain merkle tree.
 //! * `transact`: Allows the withdrawel of variable asset sizes but requires a zero-knowledge proof
-//!   of an unspent (UTXO) in an anchors merkle tree specified by TreeId.
+//!   of an unspent (UTXO) in an anchors merkle tree specified by TreeId. The relayer fee is paid in
+//!   the `fee_token` of the ext data when it names one of the assets wrapped into the vanchor
+//!   asset.
+//! * `transact_unsigned`: Withdraws without a signed origin, paying the fee from the pool to the
+//!   block author.
+//! * `swap`: Withdraws from one vanchor and deposits into another in a single call, moving the
+//!   value between their assets through the token wrapper.
+//! * `migrate_from_mixer`: Spends a note of the configured mixer and deposits its value into a
+//!   vanchor of the same asset, without paying it out to a public account.
+//!
+//! ### Permissioned Functions
+//!
+//! * `set_pause_status`: Pauses deposits or all transactions of a vanchor. This method requires the
+//!   `origin` to be root.
+//! * `set_withdrawal_limit`: Limits the amount withdrawn from a vanchor per window of blocks.
+//!   Withdrawals over the limit are held back for `WithdrawalReleaseDelay` blocks. This method
+//!   requires the `origin` to be root.
+//! * `cancel_withdrawal`: Cancels a held back withdrawal, paying its funds to a recovery account
+//!   instead of its recipient. This method requires the `origin` to be root.
+//!
+//! Once its delay has passed, a held back withdrawal can be paid out by anyone with
+//! `release_withdrawal`.
+//!
+//! ## Transaction Pool Validation
+//!
+//! [`CheckVAnchorTransact`] can be added to the runtime's `SignedExtra` to reject
+//! invalid `transact` calls before they are included in a block.
+//!
+//! ## Transaction Screening
+//!
+//! The accounts, token and amount of every transaction are passed to the configured
+//! `TransactFilter` before any state is changed, e.g. to reject transactions of
+//! accounts on the denylist of `pallet-denylist`.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -53,23 +85,42 @@ mod zerokit_utils;
 
 mod benchmarking;
 mod benchmarking_utils;
+mod extension;
+pub mod migrations;
+pub mod types;
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::DispatchError, traits::Get};
+use frame_support::{
+	dispatch::DispatchResult, ensure, pallet_prelude::DispatchError, traits::Get, weights::Weight,
+};
 use orml_traits::{
 	arithmetic::{Signed, Zero},
 	MultiCurrency, MultiCurrencyExtended,
 };
 use pallet_token_wrapper::traits::TokenWrapperInterface;
-use sp_runtime::traits::Saturating;
+use sp_runtime::{
+	traits::{CheckedSub, Saturating, UniqueSaturatedInto},
+	transaction_validity::{
+		InvalidTransaction, TransactionLongevity, TransactionPriority, TransactionValidity,
+		TransactionValidityError,
+	},
+};
 use webb_primitives::{
 	field_ops::IntoPrimeField,
 	hasher::InstanceHasher,
 	key_storage::KeyStorageInterface,
 	linkable_tree::{LinkableTreeInspector, LinkableTreeInterface},
-	traits::vanchor::{VAnchorConfig, VAnchorInspector, VAnchorInterface},
+	traits::{
+		mixer::MixerInterface,
+		transact_filter::TransactFilter,
+		vanchor::{VAnchorConfig, VAnchorInspector, VAnchorInterface},
+	},
 	types::{
-		vanchor::{ExtData, ProofData, VAnchorMetadata},
+		amount::I256,
+		vanchor::{
+			AssetSolvency, EncryptedOutputEnvelope, ExtData, PauseStatus, ProofData, TreeFlows,
+			VAnchorMetadata,
+		},
 		ElementTrait, IntoAbiToken,
 	},
 	utils::reverse_element_encoder,
@@ -84,15 +135,17 @@ use sp_std::{
 };
 
 pub mod weights;
+pub use extension::CheckVAnchorTransact;
 pub use weights::WeightInfo;
 
+use types::{PendingWithdrawal, WithdrawalWindow};
+
+/// Number of blocks an unsigned transaction stays valid in the pool
+pub const UNSIGNED_TRANSACT_LONGEVITY: TransactionLongevity = 64;
+
 /// Type alias for the orml_traits::MultiCurrency::Balance type
 pub type BalanceOf<T, I> =
 	<<T as Config<I>>::Currency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
-/// Type alias for the orml_traits::MultiCurrency::Balance type
-pub type AmountOf<T, I> = <<T as Config<I>>::Currency as MultiCurrencyExtended<
-	<T as frame_system::Config>::AccountId,
->>::Amount;
 /// Type alias for the orml_traits::MultiCurrency::CurrencyId type
 pub type CurrencyIdOf<T, I> = <<T as pallet::Config<I>>::Currency as MultiCurrency<
 	<T as frame_system::Config>::AccountId,
@@ -107,7 +160,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-
+	#[pallet::storage_version(migrations::STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -146,8 +199,12 @@ pub mod pallet {
 		/// The ethereum hash function for hashing external data (to match Solidity protocol)
 		type EthereumHasher: InstanceHasher;
 
-		/// A trait to map amount elements into a prime field.
-		type IntoField: IntoPrimeField<AmountOf<Self, I>>;
+		/// A trait to map signed 256-bit amounts into a prime field.
+		///
+		/// External amounts are [`I256`] regardless of the `Amount` of `Currency`, so
+		/// runtimes that used to provide an `IntoPrimeField<i128>` have to provide an
+		/// `IntoPrimeField<I256>` instead, e.g. `ArkworksIntoField`.
+		type IntoField: IntoPrimeField<I256>;
 
 		/// Currency type for taking deposits
 		type Currency: MultiCurrencyExtended<Self::AccountId>;
@@ -176,6 +233,63 @@ pub mod pallet {
 		#[pallet::constant]
 		type NativeCurrencyId: Get<CurrencyIdOf<Self, I>>;
 
+		/// The author of the current block, paid the fee of unsigned transactions
+		type BlockAuthor: Get<Option<Self::AccountId>>;
+
+		/// Base priority of unsigned transactions, increased by their fee
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Max priority added to `UnsignedPriority` by the fee of an unsigned transaction.
+		///
+		/// The fee counts in multiples of `MinUnsignedFee`, in units of the vanchor's asset, so
+		/// it only ranks transactions of vanchors sharing an asset. Ranking fees paid in
+		/// different assets against each other would need a price for each asset and is not
+		/// done.
+		#[pallet::constant]
+		type MaxUnsignedFeePriority: Get<TransactionPriority>;
+
+		/// Min fee of unsigned transactions, in units of the vanchor's asset. Cheaper
+		/// transactions are rejected before their proof is verified.
+		#[pallet::constant]
+		type MinUnsignedFee: Get<BalanceOf<Self, I>>;
+
+		/// Max number of unsigned transactions included in a single block
+		#[pallet::constant]
+		type MaxUnsignedTransactsPerBlock: Get<u32>;
+
+		/// Max length in bytes of each encrypted output
+		#[pallet::constant]
+		type MaxEncryptedOutputLength: Get<u32>;
+
+		/// Whether encrypted outputs must be well-formed [`EncryptedOutputEnvelope`]s
+		#[pallet::constant]
+		type RequireEncryptedOutputEnvelope: Get<bool>;
+
+		/// Length in blocks of the window over which withdrawal limits apply
+		#[pallet::constant]
+		type WithdrawalWindowLength: Get<Self::BlockNumber>;
+
+		/// Number of blocks a withdrawal over the limit is held back before release
+		#[pallet::constant]
+		type WithdrawalReleaseDelay: Get<Self::BlockNumber>;
+
+		/// Screens the accounts of a transaction before any state is changed
+		type TransactFilter: TransactFilter<
+			Self::AccountId,
+			CurrencyIdOf<Self, I>,
+			BalanceOf<Self, I>,
+		>;
+
+		/// The mixer whose notes can be migrated into the vanchors
+		type Mixer: MixerInterface<
+			Self::AccountId,
+			BalanceOf<Self, I>,
+			CurrencyIdOf<Self, I>,
+			Self::TreeId,
+			Self::Element,
+		>;
+
 		/// WeightInfo for pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -220,6 +334,61 @@ pub mod pallet {
 	pub type ProposalNonce<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::ProposalNonce, ValueQuery>;
 
+	/// The map of paused trees to the operations halted on them
+	#[pallet::storage]
+	#[pallet::getter(fn pause_status)]
+	pub type PausedVAnchors<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TreeId, PauseStatus, OptionQuery>;
+
+	/// The map of trees to the max amount withdrawn from them per window of blocks
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_limit)]
+	pub type WithdrawalLimits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TreeId, BalanceOf<T, I>, OptionQuery>;
+
+	/// The map of trees to their withdrawal volume in the current window
+	#[pallet::storage]
+	pub type WithdrawalWindows<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::TreeId,
+		WithdrawalWindow<BalanceOf<T, I>, T::BlockNumber>,
+		ValueQuery,
+	>;
+
+	/// The map of trees to the cumulative flows of funds through them
+	#[pallet::storage]
+	#[pallet::getter(fn tree_flows)]
+	pub type Flows<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TreeId, TreeFlows<BalanceOf<T, I>>, ValueQuery>;
+
+	/// Withdrawals held back for exceeding the withdrawal limit of their tree
+	#[pallet::storage]
+	#[pallet::getter(fn pending_withdrawals)]
+	pub type PendingWithdrawals<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		PendingWithdrawal<
+			T::TreeId,
+			T::AccountId,
+			CurrencyIdOf<T, I>,
+			BalanceOf<T, I>,
+			T::BlockNumber,
+		>,
+		OptionQuery,
+	>;
+
+	/// The next id of a pending withdrawal
+	#[pallet::storage]
+	pub type NextPendingWithdrawalId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// The number of unsigned transactions included in the current block
+	#[pallet::storage]
+	pub type UnsignedTransactsInBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -234,7 +403,7 @@ pub mod pallet {
 			leafs: Vec<T::Element>,
 			encrypted_output1: Vec<u8>,
 			encrypted_output2: Vec<u8>,
-			amount: AmountOf<T, I>,
+			amount: I256,
 		},
 		/// Deposit hook has executed successfully
 		Deposit {
@@ -250,6 +419,46 @@ pub mod pallet {
 		MinWithdrawAmountChanged {
 			min_withdraw_amount: BalanceOf<T, I>,
 		},
+
+		PauseStatusChanged {
+			tree_id: T::TreeId,
+			status: Option<PauseStatus>,
+		},
+
+		WithdrawalLimitChanged {
+			tree_id: T::TreeId,
+			limit: Option<BalanceOf<T, I>>,
+		},
+		/// A withdrawal exceeded the withdrawal limit and was held back
+		WithdrawalLimitExceeded {
+			tree_id: T::TreeId,
+			withdrawal_id: u32,
+			amount: BalanceOf<T, I>,
+			release_at: T::BlockNumber,
+		},
+		/// A held back withdrawal has been paid out
+		WithdrawalReleased {
+			withdrawal_id: u32,
+		},
+		/// A held back withdrawal has been cancelled and its funds paid to `refund_to`
+		WithdrawalCancelled {
+			withdrawal_id: u32,
+			refund_to: T::AccountId,
+		},
+		/// Value has been moved from one vanchor into another
+		Swap {
+			transactor: T::AccountId,
+			from_tree_id: T::TreeId,
+			to_tree_id: T::TreeId,
+			amount: BalanceOf<T, I>,
+		},
+		/// A mixer note has been deposited into a vanchor
+		MixerMigration {
+			transactor: T::AccountId,
+			mixer_id: T::TreeId,
+			tree_id: T::TreeId,
+			amount: BalanceOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -277,10 +486,84 @@ pub mod pallet {
 		InvalidPublicAmount,
 		/// Invalid nonce
 		InvalidNonce,
+		/// Unsigned transactions can only withdraw and cannot carry a refund
+		InvalidUnsignedTransaction,
+		/// Encrypted output exceeds the maximum length
+		EncryptedOutputTooLong,
+		/// Encrypted output is not a valid envelope
+		MalformedEncryptedOutput,
+		/// Deposits into this vanchor are paused
+		DepositsPaused,
+		/// All transactions on this vanchor are paused
+		VAnchorPaused,
+		/// Pending withdrawal not found
+		PendingWithdrawalNotFound,
+		/// Pending withdrawal cannot be released yet
+		WithdrawalNotReleasable,
+		/// Swap transactions must withdraw into the pallet and deposit from it
+		/// through the same token, without refunds
+		InvalidSwap,
+		/// Swapped withdrawal does not cover the deposit and its wrapping fee
+		InvalidSwapAmount,
+		/// Swap exceeds the withdrawal limit of the source vanchor
+		WithdrawalLimitReached,
+		/// Migrations must deposit exactly the mixer deposit size of the vanchor
+		/// asset, without fees or refunds
+		InvalidMixerMigration,
+		/// No ids are left for pending withdrawals
+		PendingWithdrawalIdOverflow,
+		/// Fee token is not an asset of the vanchor's wrapper pool
+		InvalidFeeToken,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			UnsignedTransactsInBlock::<T, I>::kill();
+			T::DbWeight::get().writes(1)
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::transact_unsigned { id, proof_data, ext_data } = call {
+				// Every check that binds the transaction to unspent notes and a paying
+				// fee runs first, so that only those reach the costly proof verification.
+				// The nullifiers are provided as tags, so the pool verifies at most one
+				// proof per set of notes until the transaction expires.
+				let valid_tx = Self::validate_unsigned_transact(*id, proof_data, ext_data)?;
+				// Unsigned transactions are free for the sender, so the proof is
+				// verified before the transaction is accepted into the pool.
+				Self::handle_proof_verification(proof_data)
+					.map_err(|_| InvalidTransaction::BadProof)?;
+				Ok(ValidTransaction {
+					priority: Self::unsigned_transact_priority(ext_data.fee),
+					longevity: UNSIGNED_TRANSACT_LONGEVITY,
+					..valid_tx
+				})
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+
+		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+			if let Call::transact_unsigned { id, proof_data, ext_data } = call {
+				// The proof is only verified during dispatch
+				Self::validate_unsigned_transact(*id, proof_data, ext_data)?;
+				let included = UnsignedTransactsInBlock::<T, I>::get();
+				if included >= T::MaxUnsignedTransactsPerBlock::get() {
+					return Err(InvalidTransaction::ExhaustsResources.into())
+				}
+				UnsignedTransactsInBlock::<T, I>::put(included.saturating_add(1));
+				Ok(())
+			} else {
+				Err(InvalidTransaction::Call.into())
+			}
+		}
+	}
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -345,20 +628,24 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::transact())]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::transact(ext_data.encrypted_outputs_len())
+			.saturating_add(Pallet::<T, I>::fee_token_weight(ext_data))
+			.saturating_add(Pallet::<T, I>::post_deposit_weight(proof_data)))]
 		#[pallet::call_index(1)]
 		pub fn transact(
 			origin: OriginFor<T>,
 			id: T::TreeId,
 			proof_data: ProofData<T::Element>,
-			ext_data: ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+			ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			<Self as VAnchorInterface<_>>::transact(sender, id, proof_data, ext_data)?;
 			Ok(().into())
 		}
 
-		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::register_and_transact())]
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::register_and_transact(ext_data.encrypted_outputs_len())
+			.saturating_add(Pallet::<T, I>::fee_token_weight(ext_data))
+			.saturating_add(Pallet::<T, I>::post_deposit_weight(proof_data)))]
 		#[pallet::call_index(2)]
 		pub fn register_and_transact(
 			origin: OriginFor<T>,
@@ -366,7 +653,7 @@ pub mod pallet {
 			public_key: Vec<u8>,
 			id: T::TreeId,
 			proof_data: ProofData<T::Element>,
-			ext_data: ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+			ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			<Self as VAnchorInterface<_>>::register_and_transact(
@@ -398,6 +685,182 @@ pub mod pallet {
 			<Self as VAnchorInterface<_>>::set_min_withdraw_amount(min_withdraw_amount, nonce)?;
 			Ok(().into())
 		}
+
+		/// Withdraws from the vanchor without a signed origin.
+		///
+		/// The zero-knowledge proof authorizes the transaction and `ext_data.fee` is paid
+		/// to the block author instead of the relayer, so a fresh account can withdraw
+		/// without holding any native balance.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::transact(ext_data.encrypted_outputs_len())
+			.saturating_add(Pallet::<T, I>::fee_token_weight(ext_data))
+			.saturating_add(Pallet::<T, I>::post_deposit_weight(proof_data)))]
+		#[pallet::call_index(5)]
+		pub fn transact_unsigned(
+			origin: OriginFor<T>,
+			id: T::TreeId,
+			proof_data: ProofData<T::Element>,
+			ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			ensure!(
+				!ext_data.ext_amount.is_positive() && ext_data.refund.is_zero(),
+				Error::<T, I>::InvalidUnsignedTransaction
+			);
+			let fee_recipient = T::BlockAuthor::get().unwrap_or_else(|| ext_data.relayer.clone());
+			Self::do_transact(Self::account_id(), fee_recipient, id, proof_data, ext_data)?;
+			Ok(().into())
+		}
+
+		/// Pauses deposits or all transactions of a vanchor, or resumes them when
+		/// `status` is `None`.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_pause_status())]
+		#[pallet::call_index(6)]
+		pub fn set_pause_status(
+			origin: OriginFor<T>,
+			id: T::TreeId,
+			status: Option<PauseStatus>,
+			nonce: T::ProposalNonce,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			<Self as VAnchorInterface<_>>::set_pause_status(id, status, nonce)?;
+			Ok(().into())
+		}
+
+		/// Sets the max amount withdrawn from a vanchor per window of blocks, or removes
+		/// the limit when `limit` is `None`.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::set_withdrawal_limit())]
+		#[pallet::call_index(7)]
+		pub fn set_withdrawal_limit(
+			origin: OriginFor<T>,
+			id: T::TreeId,
+			limit: Option<BalanceOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			Self::get_vanchor(id)?;
+			WithdrawalLimits::<T, I>::set(id, limit);
+			Self::deposit_event(Event::WithdrawalLimitChanged { tree_id: id, limit });
+			Ok(().into())
+		}
+
+		/// Pays out a withdrawal held back by the withdrawal limit once its delay has
+		/// passed.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::release_withdrawal())]
+		#[pallet::call_index(8)]
+		pub fn release_withdrawal(
+			origin: OriginFor<T>,
+			withdrawal_id: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let withdrawal = PendingWithdrawals::<T, I>::get(withdrawal_id)
+				.ok_or(Error::<T, I>::PendingWithdrawalNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= withdrawal.release_at,
+				Error::<T, I>::WithdrawalNotReleasable
+			);
+			ensure!(
+				PausedVAnchors::<T, I>::get(withdrawal.tree_id) != Some(PauseStatus::All),
+				Error::<T, I>::VAnchorPaused
+			);
+			let vanchor = Self::get_vanchor(withdrawal.tree_id)?;
+			Self::pay_out(&vanchor, withdrawal.token, withdrawal.amount, &withdrawal.recipient)?;
+			Self::settle_held_back_withdrawal(withdrawal.tree_id, withdrawal.amount);
+			PendingWithdrawals::<T, I>::remove(withdrawal_id);
+			Self::deposit_event(Event::WithdrawalReleased { withdrawal_id });
+			Ok(().into())
+		}
+
+		/// Cancels a withdrawal held back by the withdrawal limit, paying its funds in
+		/// the vanchor asset to `refund_to` instead of its recipient.
+		///
+		/// The notes spent by the withdrawal are already nullified, so its funds can
+		/// only be recovered off-chain from the account they are paid to.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::cancel_withdrawal())]
+		#[pallet::call_index(9)]
+		pub fn cancel_withdrawal(
+			origin: OriginFor<T>,
+			withdrawal_id: u32,
+			refund_to: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let withdrawal = PendingWithdrawals::<T, I>::get(withdrawal_id)
+				.ok_or(Error::<T, I>::PendingWithdrawalNotFound)?;
+			let vanchor = Self::get_vanchor(withdrawal.tree_id)?;
+			Self::pay_out(&vanchor, vanchor.asset, withdrawal.amount, &refund_to)?;
+			Self::settle_held_back_withdrawal(withdrawal.tree_id, withdrawal.amount);
+			PendingWithdrawals::<T, I>::remove(withdrawal_id);
+			Self::deposit_event(Event::WithdrawalCancelled { withdrawal_id, refund_to });
+			Ok(().into())
+		}
+
+		/// Withdraws from `from_id` and deposits into `to_id` atomically.
+		///
+		/// `from_ext_data` must withdraw to the pallet account and `to_ext_data` must
+		/// deposit the same token, so the value is unwrapped from the source asset and
+		/// wrapped into the destination asset without passing through a user account.
+		///
+		/// The withdrawal proof commits to its relayer, who must be the sender, so that
+		/// nobody else can pair the withdrawal with a deposit of their own.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::swap(
+			from_ext_data.encrypted_outputs_len().saturating_add(to_ext_data.encrypted_outputs_len())
+		)
+		.saturating_add(Pallet::<T, I>::fee_token_weight(from_ext_data))
+		.saturating_add(Pallet::<T, I>::fee_token_weight(to_ext_data))
+		.saturating_add(Pallet::<T, I>::post_deposit_weight(from_proof_data))
+		.saturating_add(Pallet::<T, I>::post_deposit_weight(to_proof_data)))]
+		#[pallet::call_index(10)]
+		pub fn swap(
+			origin: OriginFor<T>,
+			from_id: T::TreeId,
+			from_proof_data: ProofData<T::Element>,
+			from_ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+			to_id: T::TreeId,
+			to_proof_data: ProofData<T::Element>,
+			to_ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_swap(
+				sender,
+				from_id,
+				from_proof_data,
+				from_ext_data,
+				to_id,
+				to_proof_data,
+				to_ext_data,
+			)?;
+			Ok(().into())
+		}
+
+		/// Spends a note of the mixer `mixer_id` and deposits its value into `id`.
+		///
+		/// The mixer proof must name the pallet account as recipient and the sender
+		/// as relayer, with no fee or refund. `ext_data` must deposit the mixer
+		/// deposit size in the mixer asset, which has to be the vanchor asset.
+		#[pallet::weight(<T as pallet::Config<I>>::WeightInfo::migrate_from_mixer(ext_data.encrypted_outputs_len())
+			.saturating_add(Pallet::<T, I>::post_deposit_weight(proof_data)))]
+		#[pallet::call_index(11)]
+		pub fn migrate_from_mixer(
+			origin: OriginFor<T>,
+			mixer_id: T::TreeId,
+			mixer_proof_bytes: Vec<u8>,
+			mixer_root: T::Element,
+			mixer_nullifier_hash: T::Element,
+			id: T::TreeId,
+			proof_data: ProofData<T::Element>,
+			ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_migrate_from_mixer(
+				sender,
+				mixer_id,
+				mixer_proof_bytes,
+				mixer_root,
+				mixer_nullifier_hash,
+				id,
+				proof_data,
+				ext_data,
+			)?;
+			Ok(().into())
+		}
 	}
 }
 
@@ -408,7 +871,7 @@ pub struct VAnchorConfiguration<T: Config<I>, I: 'static>(
 
 impl<T: Config<I>, I: 'static> VAnchorConfig for VAnchorConfiguration<T, I> {
 	type AccountId = T::AccountId;
-	type Amount = AmountOf<T, I>;
+	type Amount = I256;
 	type Balance = BalanceOf<T, I>;
 	type ChainId = T::ChainId;
 	type CurrencyId = CurrencyIdOf<T, I>;
@@ -439,7 +902,7 @@ impl<T: Config<I>, I: 'static> VAnchorInterface<VAnchorConfiguration<T, I>> for
 		transactor: T::AccountId,
 		id: T::TreeId,
 		proof_data: ProofData<T::Element>,
-		ext_data: ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 	) -> Result<(), DispatchError> {
 		// First Register
 		T::KeyStorage::register(owner, public_key)?;
@@ -452,68 +915,10 @@ impl<T: Config<I>, I: 'static> VAnchorInterface<VAnchorConfiguration<T, I>> for
 		transactor: T::AccountId,
 		id: T::TreeId,
 		proof_data: ProofData<T::Element>,
-		ext_data: ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 	) -> Result<(), DispatchError> {
-		// Double check the number of roots
-		T::LinkableTree::ensure_max_edges(id, proof_data.roots.len())?;
-		// Check if local root is known
-		T::LinkableTree::ensure_known_root(id, proof_data.roots[0])?;
-		// Check if neighbor roots are known
-		T::LinkableTree::ensure_known_neighbor_roots(id, &proof_data.roots[1..].to_vec())?;
-		// Ensure all input nullifiers are unused
-		for nullifier in &proof_data.input_nullifiers {
-			Self::ensure_nullifier_unused(id, *nullifier)?;
-		}
-		// Get the vanchor
-		let vanchor = Self::get_vanchor(id)?;
-		// Compute hash of abi encoded ext_data, reduced into field from config
-		let computed_ext_data_hash = T::EthereumHasher::hash(&ext_data.encode_abi(), &[])
-			.map_err(|_| Error::<T, I>::InvalidExtData)?;
-		// Ensure that the passed external data hash matches the computed one
-		ensure!(
-			proof_data.ext_data_hash.to_bytes() == computed_ext_data_hash,
-			Error::<T, I>::InvalidExtData
-		);
-		// Making sure that public amount and fee are correct
-		ensure!(ext_data.fee < T::MaxFee::get(), Error::<T, I>::InvalidFee);
-		let ext_amount_unsigned: BalanceOf<T, I> = ext_data
-			.ext_amount
-			.abs()
-			.try_into()
-			.map_err(|_| Error::<T, I>::InvalidExtAmount)?;
-		ensure!(ext_amount_unsigned < T::MaxExtAmount::get(), Error::<T, I>::InvalidExtAmount);
-		// Verify public amount for proof
-		let (calculated_public_element, public_amount) = Self::calculate_public_amount(&ext_data)?;
-		ensure!(
-			proof_data.public_amount == calculated_public_element,
-			Error::<T, I>::InvalidPublicAmount
-		);
-		// Handle proof verification
-		Self::handle_proof_verification(&proof_data)?;
-		// Flag nullifiers as used
-		for nullifier in &proof_data.input_nullifiers {
-			Self::add_nullifier_hash(id, *nullifier)?;
-		}
-		// Handle the deposit / withdrawal shield/unshield portions
-		Self::handle_asset_action(&transactor, &vanchor, &ext_data)?;
-		// Check if the fee is non-zero
-		Self::handle_fee(&vanchor, &ext_data)?;
-		// Check if the gas-refund is non-zero
-		Self::handle_refund(&transactor, &ext_data)?;
-		// Insert output commitments into the tree
-		for comm in &proof_data.output_commitments {
-			T::LinkableTree::insert_in_order(id, *comm)?;
-		}
-		// Deposit transaction event
-		Self::deposit_event(Event::Transaction {
-			transactor,
-			tree_id: id,
-			leafs: proof_data.output_commitments,
-			encrypted_output1: ext_data.encrypted_output1,
-			encrypted_output2: ext_data.encrypted_output2,
-			amount: public_amount,
-		});
-		Ok(())
+		let fee_recipient = ext_data.relayer.clone();
+		Self::do_transact(transactor, fee_recipient, id, proof_data, ext_data)
 	}
 
 	fn add_nullifier_hash(id: T::TreeId, nullifier_hash: T::Element) -> Result<(), DispatchError> {
@@ -562,6 +967,19 @@ impl<T: Config<I>, I: 'static> VAnchorInterface<VAnchorConfiguration<T, I>> for
 		Self::deposit_event(Event::MinWithdrawAmountChanged { min_withdraw_amount });
 		Ok(())
 	}
+
+	fn set_pause_status(
+		id: T::TreeId,
+		status: Option<PauseStatus>,
+		nonce: T::ProposalNonce,
+	) -> Result<(), DispatchError> {
+		Self::get_vanchor(id)?;
+		// Nonce should be greater than the proposal nonce in storage
+		Self::validate_and_set_nonce(nonce)?;
+		PausedVAnchors::<T, I>::set(id, status);
+		Self::deposit_event(Event::PauseStatusChanged { tree_id: id, status });
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> VAnchorInspector<VAnchorConfiguration<T, I>> for Pallet<T, I> {
@@ -584,6 +1002,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The extra weight of unwrapping the fee of a transaction into its fee token
+	pub fn fee_token_weight(
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Weight {
+		match ext_data.fee_token {
+			Some(_) => {
+				let outputs_len = ext_data.encrypted_outputs_len();
+				<T as Config<I>>::WeightInfo::transact_with_fee_token(outputs_len)
+					.saturating_sub(<T as Config<I>>::WeightInfo::transact(outputs_len))
+			},
+			None => Weight::zero(),
+		}
+	}
+
+	/// The weight of the `PostDepositHook` of a transaction, which has a root for the
+	/// tree and for each of its edges
+	pub fn post_deposit_weight(proof_data: &ProofData<T::Element>) -> Weight {
+		let edges = proof_data.roots.len().saturating_sub(1);
+		T::PostDepositHook::post_deposit_weight(edges.try_into().unwrap_or(u32::MAX))
+	}
+
 	pub fn get_vanchor(
 		id: T::TreeId,
 	) -> Result<VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>, DispatchError> {
@@ -609,19 +1048,389 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	pub fn calculate_public_amount(
-		ext_data: &ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
-	) -> Result<(T::Element, AmountOf<T, I>), DispatchError> {
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(T::Element, I256), DispatchError> {
 		// Public amount can also be negative, in which
 		// case it would wrap around the field, so we should check if FIELD_SIZE -
 		// public_amount == proof_data.public_amount, in case of a negative ext_amount
-		let fee_amount =
-			AmountOf::<T, I>::try_from(ext_data.fee).map_err(|_| Error::<T, I>::InvalidFee)?;
-		let calc_public_amount = ext_data.ext_amount - fee_amount;
+		let fee: u128 = ext_data.fee.try_into().map_err(|_| Error::<T, I>::InvalidFee)?;
+		let fee_amount = I256::from(fee);
+		let calc_public_amount = ext_data
+			.ext_amount
+			.checked_sub(&fee_amount)
+			.ok_or(Error::<T, I>::InvalidPublicAmount)?;
+		// Map the public amount into the field as a 256-bit integer, like the
+		// `int256` arithmetic of the EVM contracts
 		let calc_public_amount_bytes = T::IntoField::into_field(calc_public_amount);
 		// Return the public amount as a field element
 		Ok((T::Element::from_bytes(&calc_public_amount_bytes), calc_public_amount))
 	}
 
+	/// Absolute value of an external amount, as a balance of the vanchor asset.
+	fn ext_amount_abs(ext_amount: &I256) -> Result<BalanceOf<T, I>, DispatchError> {
+		let amount = ext_amount.unsigned_abs_u128().ok_or(Error::<T, I>::InvalidExtAmount)?;
+		BalanceOf::<T, I>::try_from(amount).map_err(|_| Error::<T, I>::InvalidExtAmount.into())
+	}
+
+	/// Executes a transaction once the caller has decided who receives the fee.
+	pub fn do_transact(
+		transactor: T::AccountId,
+		fee_recipient: T::AccountId,
+		id: T::TreeId,
+		proof_data: ProofData<T::Element>,
+		ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(), DispatchError> {
+		// Run all checks that do not require verifying the proof
+		let (vanchor, public_amount) = Self::validate_transact(id, &proof_data, &ext_data)?;
+		Self::filter_transact(&transactor, &ext_data)?;
+		// Handle proof verification
+		Self::handle_proof_verification(&proof_data)?;
+		// Flag nullifiers as used
+		for nullifier in &proof_data.input_nullifiers {
+			Self::add_nullifier_hash(id, *nullifier)?;
+		}
+		// Handle the deposit / withdrawal shield/unshield portions, holding back
+		// withdrawals over the withdrawal limit of the vanchor
+		if ext_data.ext_amount.is_negative() {
+			let amount: BalanceOf<T, I> = Self::ext_amount_abs(&ext_data.ext_amount)?;
+			if Self::try_record_withdrawal(id, amount) {
+				Self::handle_asset_action(id, &transactor, &vanchor, &ext_data)?;
+			} else {
+				Self::hold_back_withdrawal(id, &ext_data, amount)?;
+			}
+		} else {
+			Self::handle_asset_action(id, &transactor, &vanchor, &ext_data)?;
+		}
+		// Check if the fee is non-zero
+		Self::handle_fee(id, &vanchor, &ext_data, &fee_recipient)?;
+		// Check if the gas-refund is non-zero
+		Self::handle_refund(&transactor, &ext_data)?;
+		Self::insert_outputs(transactor, id, proof_data, ext_data, public_amount)
+	}
+
+	/// Executes a withdrawal from one vanchor and a deposit into another, paying
+	/// the deposit with the withdrawn funds.
+	pub fn do_swap(
+		transactor: T::AccountId,
+		from_id: T::TreeId,
+		from_proof_data: ProofData<T::Element>,
+		from_ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		to_id: T::TreeId,
+		to_proof_data: ProofData<T::Element>,
+		to_ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(), DispatchError> {
+		// The withdrawal must pay out to the pallet and the deposit must take the
+		// same token from it
+		ensure!(from_id != to_id, Error::<T, I>::InvalidSwap);
+		ensure!(
+			from_ext_data.ext_amount.is_negative() && to_ext_data.ext_amount.is_positive(),
+			Error::<T, I>::InvalidSwap
+		);
+		ensure!(
+			from_ext_data.recipient == Self::account_id() &&
+				from_ext_data.token == to_ext_data.token,
+			Error::<T, I>::InvalidSwap
+		);
+		// Only the relayer committed to by the withdrawal can choose its deposit
+		ensure!(from_ext_data.relayer == transactor, Error::<T, I>::InvalidSwap);
+		ensure!(
+			from_ext_data.refund.is_zero() && to_ext_data.refund.is_zero(),
+			Error::<T, I>::InvalidSwap
+		);
+		// Run all checks that do not require verifying the proofs
+		let (from_vanchor, from_public_amount) =
+			Self::validate_transact(from_id, &from_proof_data, &from_ext_data)?;
+		let (to_vanchor, to_public_amount) =
+			Self::validate_transact(to_id, &to_proof_data, &to_ext_data)?;
+		Self::filter_transact(&transactor, &from_ext_data)?;
+		Self::filter_transact(&transactor, &to_ext_data)?;
+		// The withdrawn amount has to cover the deposit and the cost of wrapping it
+		let token = from_ext_data.token;
+		let withdrawn: BalanceOf<T, I> = Self::ext_amount_abs(&from_ext_data.ext_amount)?;
+		let deposited: BalanceOf<T, I> = Self::ext_amount_abs(&to_ext_data.ext_amount)?;
+		let wrapping_fee = if token != to_vanchor.asset {
+			T::TokenWrapper::wrapping_fee(deposited, to_vanchor.asset)?
+		} else {
+			Zero::zero()
+		};
+		ensure!(
+			withdrawn == deposited.saturating_add(wrapping_fee),
+			Error::<T, I>::InvalidSwapAmount
+		);
+		// Handle proof verification
+		Self::handle_proof_verification(&from_proof_data)?;
+		Self::handle_proof_verification(&to_proof_data)?;
+		// Flag nullifiers as used
+		for nullifier in &from_proof_data.input_nullifiers {
+			Self::add_nullifier_hash(from_id, *nullifier)?;
+		}
+		for nullifier in &to_proof_data.input_nullifiers {
+			Self::add_nullifier_hash(to_id, *nullifier)?;
+		}
+		// A swap cannot be held back, so it fails if it exceeds the withdrawal limit
+		ensure!(
+			Self::try_record_withdrawal(from_id, withdrawn),
+			Error::<T, I>::WithdrawalLimitReached
+		);
+		// Move the value between the vanchor assets through the pallet account
+		let pallet_account = Self::account_id();
+		Self::withdraw_to(from_id, &from_vanchor, token, withdrawn, &pallet_account)?;
+		Self::deposit_from(to_id, &to_vanchor, token, deposited, &pallet_account)?;
+		// Pay the relayer fees of both sides
+		Self::handle_fee(from_id, &from_vanchor, &from_ext_data, &from_ext_data.relayer)?;
+		Self::handle_fee(to_id, &to_vanchor, &to_ext_data, &to_ext_data.relayer)?;
+		Self::insert_outputs(
+			transactor.clone(),
+			from_id,
+			from_proof_data,
+			from_ext_data,
+			from_public_amount,
+		)?;
+		Self::insert_outputs(
+			transactor.clone(),
+			to_id,
+			to_proof_data,
+			to_ext_data,
+			to_public_amount,
+		)?;
+		Self::deposit_event(Event::Swap {
+			transactor,
+			from_tree_id: from_id,
+			to_tree_id: to_id,
+			amount: deposited,
+		});
+		Ok(())
+	}
+
+	/// Withdraws a mixer note into the pallet and deposits it into a vanchor.
+	pub fn do_migrate_from_mixer(
+		transactor: T::AccountId,
+		mixer_id: T::TreeId,
+		mixer_proof_bytes: Vec<u8>,
+		mixer_root: T::Element,
+		mixer_nullifier_hash: T::Element,
+		id: T::TreeId,
+		proof_data: ProofData<T::Element>,
+		ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(), DispatchError> {
+		// The deposit must be exactly the mixer note, paid in the mixer asset
+		let (deposit_size, asset) = T::Mixer::deposit_info(mixer_id)?;
+		ensure!(
+			ext_data.ext_amount.is_positive() &&
+				ext_data.fee.is_zero() &&
+				ext_data.refund.is_zero(),
+			Error::<T, I>::InvalidMixerMigration
+		);
+		let deposited: BalanceOf<T, I> = Self::ext_amount_abs(&ext_data.ext_amount)?;
+		ensure!(
+			deposited == deposit_size && ext_data.token == asset,
+			Error::<T, I>::InvalidMixerMigration
+		);
+		// Run all checks that do not require verifying the proof
+		let (vanchor, public_amount) = Self::validate_transact(id, &proof_data, &ext_data)?;
+		ensure!(vanchor.asset == asset, Error::<T, I>::InvalidMixerMigration);
+		Self::filter_transact(&transactor, &ext_data)?;
+		// Handle proof verification
+		Self::handle_proof_verification(&proof_data)?;
+		// Flag nullifiers as used
+		for nullifier in &proof_data.input_nullifiers {
+			Self::add_nullifier_hash(id, *nullifier)?;
+		}
+		// The mixer verifies its proof, spends the note and pays it to the pallet,
+		// where it is locked in the vanchor
+		let pallet_account = Self::account_id();
+		T::Mixer::withdraw(
+			transactor.clone(),
+			mixer_id,
+			&mixer_proof_bytes,
+			mixer_root,
+			mixer_nullifier_hash,
+			pallet_account.clone(),
+			transactor.clone(),
+			Zero::zero(),
+			Zero::zero(),
+		)?;
+		Self::deposit_from(id, &vanchor, asset, deposited, &pallet_account)?;
+		Self::insert_outputs(transactor.clone(), id, proof_data, ext_data, public_amount)?;
+		Self::deposit_event(Event::MixerMigration {
+			transactor,
+			mixer_id,
+			tree_id: id,
+			amount: deposited,
+		});
+		Ok(())
+	}
+
+	/// Inserts the output commitments of a transaction into the tree and emits
+	/// its `Transaction` event.
+	fn insert_outputs(
+		transactor: T::AccountId,
+		id: T::TreeId,
+		proof_data: ProofData<T::Element>,
+		ext_data: ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		public_amount: I256,
+	) -> Result<(), DispatchError> {
+		// Insert output commitments into the tree
+		for comm in &proof_data.output_commitments {
+			T::LinkableTree::insert_in_order(id, *comm)?;
+		}
+		// Notify the hook once the tree holds all the outputs
+		if let Some(leaf) = proof_data.output_commitments.last() {
+			T::PostDepositHook::post_deposit(transactor.clone(), id, *leaf)?;
+		}
+		// Deposit transaction event
+		Self::deposit_event(Event::Transaction {
+			transactor,
+			tree_id: id,
+			leafs: proof_data.output_commitments,
+			encrypted_output1: ext_data.encrypted_output1,
+			encrypted_output2: ext_data.encrypted_output2,
+			amount: public_amount,
+		});
+		Ok(())
+	}
+
+	/// Checks an unsigned transaction without verifying its proof.
+	pub fn validate_unsigned_transact(
+		id: T::TreeId,
+		proof_data: &ProofData<T::Element>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> TransactionValidity {
+		// Deposits and refunds need funds from a signing account
+		if ext_data.ext_amount.is_positive() || !ext_data.refund.is_zero() {
+			return Err(InvalidTransaction::Call.into())
+		}
+		// The fee is the only payment for including the transaction
+		if ext_data.fee.is_zero() || ext_data.fee < T::MinUnsignedFee::get() {
+			return Err(InvalidTransaction::Payment.into())
+		}
+		// Each note can only be spent once, even within a single transaction
+		let nullifiers = &proof_data.input_nullifiers;
+		if nullifiers.iter().enumerate().any(|(i, n)| nullifiers[..i].contains(n)) {
+			return Err(InvalidTransaction::Stale.into())
+		}
+		Self::validate_transact_unverified(id, proof_data, ext_data)
+	}
+
+	/// Returns the pool priority of an unsigned transaction paying `fee`: the base
+	/// priority plus the fee in multiples of `MinUnsignedFee`, capped at
+	/// `MaxUnsignedFeePriority`.
+	pub fn unsigned_transact_priority(fee: BalanceOf<T, I>) -> TransactionPriority {
+		let min_fee = T::MinUnsignedFee::get();
+		let multiples = if min_fee.is_zero() { fee } else { fee / min_fee };
+		let fee_priority: TransactionPriority = multiples.unique_saturated_into();
+		T::UnsignedPriority::get()
+			.saturating_add(fee_priority.min(T::MaxUnsignedFeePriority::get()))
+	}
+
+	/// Performs every check of a `transact` call except for the zero-knowledge
+	/// proof verification, returning the vanchor and the signed public amount.
+	///
+	/// This only reads storage, which makes it usable from the transaction pool
+	/// through [`CheckVAnchorTransact`] as well as during dispatch.
+	pub fn validate_transact(
+		id: T::TreeId,
+		proof_data: &ProofData<T::Element>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>, I256), DispatchError> {
+		// Check the encrypted outputs before doing any hashing
+		Self::ensure_valid_encrypted_outputs(ext_data)?;
+		// Double check the number of roots
+		T::LinkableTree::ensure_max_edges(id, proof_data.roots.len())?;
+		// Check if local root is known
+		T::LinkableTree::ensure_known_root(id, proof_data.roots[0])?;
+		// Check if neighbor roots are known
+		T::LinkableTree::ensure_known_neighbor_roots(id, &proof_data.roots[1..].to_vec())?;
+		// Ensure all input nullifiers are unused
+		for nullifier in &proof_data.input_nullifiers {
+			Self::ensure_nullifier_unused(id, *nullifier)?;
+		}
+		// Get the vanchor
+		let vanchor = Self::get_vanchor(id)?;
+		// Ensure the vanchor accepts this kind of transaction
+		match PausedVAnchors::<T, I>::get(id) {
+			Some(PauseStatus::All) => return Err(Error::<T, I>::VAnchorPaused.into()),
+			Some(PauseStatus::Deposits) =>
+				ensure!(!ext_data.ext_amount.is_positive(), Error::<T, I>::DepositsPaused),
+			None => {},
+		}
+		// The fee can only be unwrapped into an asset of the vanchor's wrapper pool
+		if let Some(fee_token) = ext_data.fee_token {
+			ensure!(
+				fee_token == vanchor.asset ||
+					T::TokenWrapper::contains_asset(vanchor.asset, fee_token),
+				Error::<T, I>::InvalidFeeToken
+			);
+		}
+		// Compute hash of abi encoded ext_data, reduced into field from config
+		let computed_ext_data_hash = T::EthereumHasher::hash(&ext_data.encode_abi(), &[])
+			.map_err(|_| Error::<T, I>::InvalidExtData)?;
+		// Ensure that the passed external data hash matches the computed one
+		ensure!(
+			proof_data.ext_data_hash.to_bytes() == computed_ext_data_hash,
+			Error::<T, I>::InvalidExtData
+		);
+		// Making sure that public amount and fee are correct
+		ensure!(ext_data.fee < T::MaxFee::get(), Error::<T, I>::InvalidFee);
+		let ext_amount_unsigned: BalanceOf<T, I> = Self::ext_amount_abs(&ext_data.ext_amount)?;
+		ensure!(ext_amount_unsigned < T::MaxExtAmount::get(), Error::<T, I>::InvalidExtAmount);
+		// Check the deposit / withdrawal limits
+		if ext_data.ext_amount.is_positive() {
+			ensure!(
+				ext_amount_unsigned <= MaxDepositAmount::<T, I>::get(),
+				Error::<T, I>::InvalidDepositAmount
+			);
+		} else if ext_data.ext_amount.is_negative() {
+			ensure!(
+				ext_amount_unsigned >= MinWithdrawAmount::<T, I>::get(),
+				Error::<T, I>::InvalidWithdrawAmount
+			);
+		}
+		// Verify public amount for proof
+		let (calculated_public_element, public_amount) = Self::calculate_public_amount(ext_data)?;
+		ensure!(
+			proof_data.public_amount == calculated_public_element,
+			Error::<T, I>::InvalidPublicAmount
+		);
+		Ok((vanchor, public_amount))
+	}
+
+	/// Passes the accounts, token and absolute amount of a transaction to the
+	/// `TransactFilter`.
+	fn filter_transact(
+		transactor: &T::AccountId,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(), DispatchError> {
+		let amount: BalanceOf<T, I> = Self::ext_amount_abs(&ext_data.ext_amount)?;
+		T::TransactFilter::filter(
+			transactor,
+			&ext_data.recipient,
+			&ext_data.relayer,
+			ext_data.token,
+			amount,
+		)
+	}
+
+	/// Ensures both encrypted outputs are within the length limit and, if required,
+	/// are well-formed envelopes.
+	pub fn ensure_valid_encrypted_outputs(
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+	) -> Result<(), DispatchError> {
+		for output in [&ext_data.encrypted_output1, &ext_data.encrypted_output2] {
+			ensure!(
+				output.len() <= T::MaxEncryptedOutputLength::get() as usize,
+				Error::<T, I>::EncryptedOutputTooLong
+			);
+			if T::RequireEncryptedOutputEnvelope::get() {
+				ensure!(
+					EncryptedOutputEnvelope::from_bytes(output).is_some(),
+					Error::<T, I>::MalformedEncryptedOutput
+				);
+			}
+		}
+		Ok(())
+	}
+
 	pub fn handle_proof_verification(
 		proof_data: &ProofData<T::Element>,
 	) -> Result<(), DispatchError> {
@@ -652,18 +1461,34 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	pub fn handle_fee(
+		id: T::TreeId,
 		vanchor: &VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>,
-		ext_data: &ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		fee_recipient: &T::AccountId,
 	) -> Result<(), DispatchError> {
 		let fee_exists = ext_data.fee > BalanceOf::<T, I>::zero();
 		if fee_exists {
-			// Send fee to the relayer
-			<T as Config<I>>::Currency::transfer(
-				vanchor.asset,
-				&Self::account_id(),
-				&ext_data.relayer,
-				ext_data.fee,
-			)?;
+			// Send fee to the relayer (or block author for unsigned transactions),
+			// unwrapped into the fee token if one is named
+			match ext_data.fee_token {
+				Some(fee_token) if fee_token != vanchor.asset => T::TokenWrapper::unwrap(
+					Self::account_id(),
+					vanchor.asset,
+					fee_token,
+					ext_data.fee,
+					fee_recipient.clone(),
+				)?,
+				_ => <T as Config<I>>::Currency::transfer(
+					vanchor.asset,
+					&Self::account_id(),
+					fee_recipient,
+					ext_data.fee,
+				)?,
+			}
+			Flows::<T, I>::mutate(id, |flows| {
+				flows.fees_paid = flows.fees_paid.saturating_add(ext_data.fee);
+				flows.locked = flows.locked.saturating_sub(ext_data.fee);
+			});
 		}
 
 		Ok(())
@@ -671,7 +1496,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	pub fn handle_refund(
 		transactor: &T::AccountId,
-		ext_data: &ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 	) -> Result<(), DispatchError> {
 		let refund_exists = ext_data.refund > BalanceOf::<T, I>::zero();
 		if refund_exists {
@@ -688,79 +1513,230 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	pub fn handle_asset_action(
+		id: T::TreeId,
 		transactor: &T::AccountId,
 		vanchor: &VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>,
-		ext_data: &ExtData<T::AccountId, AmountOf<T, I>, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
 	) -> Result<(), DispatchError> {
 		// If external amount is positive then we are depositing
 		let is_deposit = ext_data.ext_amount.is_positive();
 		// If external amount is negative then we are withdrawing
 		let is_negative = ext_data.ext_amount.is_negative();
 		// Get the absolute amount for either action
-		let abs_amount: BalanceOf<T, I> = ext_data
-			.ext_amount
-			.abs()
-			.try_into()
-			.map_err(|_| Error::<T, I>::InvalidExtAmount)?;
+		let abs_amount: BalanceOf<T, I> = Self::ext_amount_abs(&ext_data.ext_amount)?;
 		// Check if the transaction is a deposit or a withdrawal
 		if is_deposit {
-			ensure!(
-				abs_amount <= MaxDepositAmount::<T, I>::get(),
-				Error::<T, I>::InvalidDepositAmount
-			);
-			// If the token is not the same as the vanchor asset then
-			// we need to wrap the tokens into the vanchor asset
-			if ext_data.token != vanchor.asset {
-				// Wrap tokens from the transactor's account
-				T::TokenWrapper::wrap(
-					transactor.clone(),
-					ext_data.token,
-					vanchor.asset,
-					abs_amount,
-					Self::account_id(),
-				)?;
-			} else {
-				// Deposit tokens to the pallet from the transactor's account
-				<T as Config<I>>::Currency::transfer(
-					vanchor.asset,
-					transactor,
-					&Self::account_id(),
-					abs_amount,
-				)?;
-			}
+			Self::deposit_from(id, vanchor, ext_data.token, abs_amount, transactor)?;
 		} else if is_negative {
-			ensure!(
-				abs_amount >= MinWithdrawAmount::<T, I>::get(),
-				Error::<T, I>::InvalidWithdrawAmount
-			);
-			// If the token is not the same as the vanchor asset then
-			// we need to unwrap the tokens from the vanchor asset
-			if ext_data.token != vanchor.asset {
-				// Unwrap to recipient account
-				T::TokenWrapper::unwrap(
-					Self::account_id(),
-					vanchor.asset,
-					ext_data.token,
-					abs_amount,
-					ext_data.recipient.clone(),
-				)?;
-			} else {
-				// Withdraw to recipient account
-				<T as Config<I>>::Currency::transfer(
-					vanchor.asset,
-					&Self::account_id(),
-					&ext_data.recipient,
-					abs_amount,
-				)?;
+			Self::withdraw_to(id, vanchor, ext_data.token, abs_amount, &ext_data.recipient)?;
+		}
+
+		Ok(())
+	}
+
+	/// Takes `amount` of `token` from `depositor` into the pallet, wrapped into the
+	/// vanchor asset.
+	pub fn deposit_from(
+		id: T::TreeId,
+		vanchor: &VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>,
+		token: CurrencyIdOf<T, I>,
+		amount: BalanceOf<T, I>,
+		depositor: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		// If the token is not the same as the vanchor asset then
+		// we need to wrap the tokens into the vanchor asset
+		if token != vanchor.asset {
+			// Wrap tokens from the depositor's account
+			T::TokenWrapper::wrap(
+				depositor.clone(),
+				token,
+				vanchor.asset,
+				amount,
+				Self::account_id(),
+			)?;
+		} else {
+			// Deposit tokens to the pallet from the depositor's account
+			<T as Config<I>>::Currency::transfer(
+				vanchor.asset,
+				depositor,
+				&Self::account_id(),
+				amount,
+			)?;
+		}
+		Flows::<T, I>::mutate(id, |flows| {
+			flows.deposited = flows.deposited.saturating_add(amount);
+			flows.locked = flows.locked.saturating_add(amount);
+		});
+		Ok(())
+	}
+
+	/// Pays `amount` of the vanchor asset out of the pallet, unwrapped into `token`,
+	/// and records it as withdrawn from the tree.
+	pub fn withdraw_to(
+		id: T::TreeId,
+		vanchor: &VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>,
+		token: CurrencyIdOf<T, I>,
+		amount: BalanceOf<T, I>,
+		recipient: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Self::pay_out(vanchor, token, amount, recipient)?;
+		Flows::<T, I>::mutate(id, |flows| {
+			flows.withdrawn = flows.withdrawn.saturating_add(amount);
+			flows.locked = flows.locked.saturating_sub(amount);
+		});
+		Ok(())
+	}
+
+	/// Pays `amount` of the vanchor asset out of the pallet, unwrapped into `token`,
+	/// without touching the flows of the tree.
+	fn pay_out(
+		vanchor: &VAnchorMetadata<T::AccountId, CurrencyIdOf<T, I>>,
+		token: CurrencyIdOf<T, I>,
+		amount: BalanceOf<T, I>,
+		recipient: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		// If the token is not the same as the vanchor asset then
+		// we need to unwrap the tokens from the vanchor asset
+		if token != vanchor.asset {
+			// Unwrap to recipient account
+			T::TokenWrapper::unwrap(
+				Self::account_id(),
+				vanchor.asset,
+				token,
+				amount,
+				recipient.clone(),
+			)?;
+		} else {
+			// Withdraw to recipient account
+			<T as Config<I>>::Currency::transfer(
+				vanchor.asset,
+				&Self::account_id(),
+				recipient,
+				amount,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Records a held back withdrawal of a tree as paid out.
+	fn settle_held_back_withdrawal(id: T::TreeId, amount: BalanceOf<T, I>) {
+		Flows::<T, I>::mutate(id, |flows| {
+			flows.withdrawn = flows.withdrawn.saturating_add(amount);
+			flows.held_back = flows.held_back.saturating_sub(amount);
+		});
+	}
+
+	/// Compares, for each asset, the holdings of the pallet account with the sum of
+	/// the balances locked in the vanchors of that asset, including their held back
+	/// withdrawals.
+	pub fn solvency() -> Vec<AssetSolvency<CurrencyIdOf<T, I>, BalanceOf<T, I>>> {
+		let mut locked_by_asset: Vec<(CurrencyIdOf<T, I>, BalanceOf<T, I>)> = Vec::new();
+		for (id, vanchor) in VAnchors::<T, I>::iter() {
+			let flows = Flows::<T, I>::get(id);
+			let locked = flows.locked.saturating_add(flows.held_back);
+			match locked_by_asset.iter_mut().find(|(asset, _)| *asset == vanchor.asset) {
+				Some((_, total)) => *total = total.saturating_add(locked),
+				None => locked_by_asset.push((vanchor.asset, locked)),
 			}
 		}
+		locked_by_asset
+			.into_iter()
+			.map(|(asset, locked)| {
+				let held = <T as Config<I>>::Currency::total_balance(asset, &Self::account_id());
+				AssetSolvency::new(asset, locked, held)
+			})
+			.collect()
+	}
+
+	/// Checks that the pallet account covers the balances locked in every vanchor.
+	pub fn is_solvent() -> bool {
+		Self::solvency().iter().all(AssetSolvency::is_solvent)
+	}
+
+	/// Returns the withdrawal window of a tree, starting a new one if the last
+	/// window has ended.
+	fn current_withdrawal_window(
+		id: T::TreeId,
+	) -> WithdrawalWindow<BalanceOf<T, I>, T::BlockNumber> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let window = WithdrawalWindows::<T, I>::get(id);
+		if now >= window.start.saturating_add(T::WithdrawalWindowLength::get()) {
+			WithdrawalWindow { start: now, withdrawn: Zero::zero() }
+		} else {
+			window
+		}
+	}
+
+	/// Amount that can still be withdrawn from a tree in the current window, or
+	/// `None` if withdrawals are not limited.
+	pub fn remaining_withdrawal_capacity(id: T::TreeId) -> Option<BalanceOf<T, I>> {
+		WithdrawalLimits::<T, I>::get(id)
+			.map(|limit| limit.saturating_sub(Self::current_withdrawal_window(id).withdrawn))
+	}
 
+	/// Adds a withdrawal to the volume of the current window, returning `false`
+	/// without recording it if it exceeds the withdrawal limit of the tree.
+	fn try_record_withdrawal(id: T::TreeId, amount: BalanceOf<T, I>) -> bool {
+		let limit = match WithdrawalLimits::<T, I>::get(id) {
+			Some(limit) => limit,
+			None => return true,
+		};
+		let mut window = Self::current_withdrawal_window(id);
+		let withdrawn = window.withdrawn.saturating_add(amount);
+		if withdrawn > limit {
+			return false
+		}
+		window.withdrawn = withdrawn;
+		WithdrawalWindows::<T, I>::insert(id, window);
+		true
+	}
+
+	/// Stores a withdrawal for delayed release instead of paying it out.
+	fn hold_back_withdrawal(
+		id: T::TreeId,
+		ext_data: &ExtData<T::AccountId, I256, BalanceOf<T, I>, CurrencyIdOf<T, I>>,
+		amount: BalanceOf<T, I>,
+	) -> Result<(), DispatchError> {
+		let withdrawal_id = NextPendingWithdrawalId::<T, I>::get();
+		let next_withdrawal_id =
+			withdrawal_id.checked_add(1).ok_or(Error::<T, I>::PendingWithdrawalIdOverflow)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let release_at = now.saturating_add(T::WithdrawalReleaseDelay::get());
+		PendingWithdrawals::<T, I>::insert(
+			withdrawal_id,
+			PendingWithdrawal {
+				tree_id: id,
+				recipient: ext_data.recipient.clone(),
+				token: ext_data.token,
+				amount,
+				release_at,
+			},
+		);
+		NextPendingWithdrawalId::<T, I>::put(next_withdrawal_id);
+		Flows::<T, I>::mutate(id, |flows| {
+			flows.locked = flows.locked.saturating_sub(amount);
+			flows.held_back = flows.held_back.saturating_add(amount);
+		});
+		Self::deposit_event(Event::WithdrawalLimitExceeded {
+			tree_id: id,
+			withdrawal_id,
+			amount,
+			release_at,
+		});
 		Ok(())
 	}
 }
 
+/// Hook run after the output commitments of a transaction are inserted into a
+/// vanchor's tree.
 pub trait PostDepositHook<T: Config<I>, I: 'static> {
+	/// Called with the transactor, the tree and the last leaf inserted into it.
 	fn post_deposit(depositor: T::AccountId, id: T::TreeId, leaf: T::Element) -> DispatchResult;
+
+	/// The weight of `post_deposit` for a tree with `max_edges` edges.
+	fn post_deposit_weight(_max_edges: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 impl<T: Config<I>, I: 'static> PostDepositHook<T, I> for () {
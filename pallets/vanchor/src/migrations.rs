@@ -0,0 +1,62 @@
+//! Storage migrations of the VAnchor pallet
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec};
+
+/// The storage version set by the latest migration of the pallet.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Seeds the `Flows` of the vanchors created before it was introduced from the
+/// current balances of the pallet account.
+///
+/// Held back withdrawals are counted against their tree. The rest of the pallet
+/// account's balance in an asset is counted as locked in the vanchor of that asset
+/// when it is the only one. How that balance is split between several vanchors of
+/// the same asset is not recorded anywhere, so their `locked` is left at zero and
+/// `solvency` reports the balance as surplus. The cumulative totals start counting
+/// from the upgrade.
+pub struct SeedFlows<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for SeedFlows<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T, I>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1)
+		}
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+
+		for (_, withdrawal) in PendingWithdrawals::<T, I>::iter() {
+			Flows::<T, I>::mutate(withdrawal.tree_id, |flows| {
+				flows.held_back = flows.held_back.saturating_add(withdrawal.amount);
+			});
+			reads += 2;
+			writes += 1;
+		}
+
+		let mut trees_by_asset: Vec<(CurrencyIdOf<T, I>, Vec<T::TreeId>)> = Vec::new();
+		for (id, vanchor) in VAnchors::<T, I>::iter() {
+			reads += 1;
+			match trees_by_asset.iter_mut().find(|(asset, _)| *asset == vanchor.asset) {
+				Some((_, trees)) => trees.push(id),
+				None => trees_by_asset.push((vanchor.asset, vec![id])),
+			}
+		}
+		for (asset, trees) in trees_by_asset {
+			if let [id] = trees[..] {
+				let held =
+					<T as Config<I>>::Currency::total_balance(asset, &Pallet::<T, I>::account_id());
+				Flows::<T, I>::mutate(id, |flows| {
+					flows.locked = held.saturating_sub(flows.held_back);
+				});
+				reads += 2;
+				writes += 1;
+			}
+		}
+
+		STORAGE_VERSION.put::<Pallet<T, I>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -67,8 +67,11 @@ frame_support::construct_runtime!(
 		VAnchorVerifier1: pallet_vanchor_verifier::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
 		VAnchorVerifier2: pallet_vanchor_verifier::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
 
-		VAnchor1: pallet_vanchor::<Instance1>::{Pallet, Call, Storage, Event<T>},
-		VAnchor2: pallet_vanchor::<Instance2>::{Pallet, Call, Storage, Event<T>},
+		VAnchor1: pallet_vanchor::<Instance1>::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
+		VAnchor2: pallet_vanchor::<Instance2>::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
+
+		MixerVerifier: pallet_verifier::{Pallet, Call, Storage, Event<T>},
+		Mixer1: pallet_mixer::<Instance1>::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -358,6 +361,34 @@ parameter_types! {
 	pub const MaxFee: Balance = 5;
 	pub const MaxExtAmount: Balance = 21;
 	pub const MaxCurrencyId: AssetId = AssetId::MAX - 1;
+	pub BlockAuthor: Option<AccountId> = Some(AccountId::new([5u8; 32]));
+	pub const UnsignedPriority: u64 = 1_000;
+	pub const MaxUnsignedFeePriority: u64 = 100;
+	pub const MinUnsignedFee: Balance = 1;
+	pub const MaxUnsignedTransactsPerBlock: u32 = 2;
+	pub const MaxEncryptedOutputLength: u32 = 1_024;
+	pub static RequireEncryptedOutputEnvelope: bool = false;
+	pub const WithdrawalWindowLength: u64 = 10;
+	pub const WithdrawalReleaseDelay: u64 = 5;
+	pub static DeniedAccount: Option<AccountId> = None;
+}
+
+/// Rejects transactions in which `DeniedAccount` takes part.
+pub struct MockTransactFilter;
+impl TransactFilter<AccountId, AssetId, Balance> for MockTransactFilter {
+	fn filter(
+		transactor: &AccountId,
+		recipient: &AccountId,
+		relayer: &AccountId,
+		_: AssetId,
+		_: Balance,
+	) -> DispatchResult {
+		match DeniedAccount::get() {
+			Some(denied) if [transactor, recipient, relayer].contains(&&denied) =>
+				Err(DispatchError::Other("Denied account")),
+			_ => Ok(()),
+		}
+	}
 }
 
 type VAnchorInstance1 = pallet_vanchor::Instance1;
@@ -377,6 +408,17 @@ impl pallet_vanchor::Config<VAnchorInstance1> for Test {
 	type PostDepositHook = ();
 	type VAnchorVerifier = VAnchorVerifier1;
 	type KeyStorage = KeyStorage;
+	type BlockAuthor = BlockAuthor;
+	type UnsignedPriority = UnsignedPriority;
+	type MaxUnsignedFeePriority = MaxUnsignedFeePriority;
+	type MinUnsignedFee = MinUnsignedFee;
+	type MaxUnsignedTransactsPerBlock = MaxUnsignedTransactsPerBlock;
+	type MaxEncryptedOutputLength = MaxEncryptedOutputLength;
+	type RequireEncryptedOutputEnvelope = RequireEncryptedOutputEnvelope;
+	type WithdrawalWindowLength = WithdrawalWindowLength;
+	type WithdrawalReleaseDelay = WithdrawalReleaseDelay;
+	type TransactFilter = MockTransactFilter;
+	type Mixer = Mixer1;
 	type WeightInfo = ();
 }
 
@@ -397,6 +439,42 @@ impl pallet_vanchor::Config<VAnchorInstance2> for Test {
 	type PostDepositHook = ();
 	type VAnchorVerifier = VAnchorVerifier2;
 	type KeyStorage = KeyStorage;
+	type BlockAuthor = BlockAuthor;
+	type UnsignedPriority = UnsignedPriority;
+	type MaxUnsignedFeePriority = MaxUnsignedFeePriority;
+	type MinUnsignedFee = MinUnsignedFee;
+	type MaxUnsignedTransactsPerBlock = MaxUnsignedTransactsPerBlock;
+	type MaxEncryptedOutputLength = MaxEncryptedOutputLength;
+	type RequireEncryptedOutputEnvelope = RequireEncryptedOutputEnvelope;
+	type WithdrawalWindowLength = WithdrawalWindowLength;
+	type WithdrawalReleaseDelay = WithdrawalReleaseDelay;
+	type TransactFilter = MockTransactFilter;
+	type Mixer = ();
+	type WeightInfo = ();
+}
+
+impl pallet_verifier::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxParameterLength = ConstU32<10000>;
+	type Verifier = ArkworksVerifierBn254;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MixerPalletId: PalletId = PalletId(*b"py/mixer");
+}
+
+impl pallet_mixer::Config<pallet_mixer::Instance1> for Test {
+	type Currency = Currencies;
+	type RuntimeEvent = RuntimeEvent;
+	type NativeCurrencyId = NativeCurrencyId;
+	type MaxFee = MaxFee;
+	type PalletId = MixerPalletId;
+	type Tree = MerkleTree1;
+	type Verifier = MixerVerifier;
+	type ArbitraryHasher = Keccak256HasherBn254;
+	type TransactFilter = MockTransactFilter;
 	type WeightInfo = ();
 }
 
@@ -12,10 +12,10 @@ use ark_serialize::CanonicalSerialize;
 use ark_std::{rand::thread_rng, vec::Vec};
 use arkworks_native_gadgets::poseidon::Poseidon;
 use arkworks_setups::{
-	common::{setup_params, setup_tree_and_create_path},
-	r1cs::vanchor::VAnchorR1CSProver,
+	common::{setup_params, setup_tree_and_create_path, Leaf, MixerProof},
+	r1cs::{mixer::MixerR1CSProver, vanchor::VAnchorR1CSProver},
 	utxo::Utxo,
-	Curve, VAnchorProver,
+	Curve, MixerProver, VAnchorProver,
 };
 use circom_proving::circom_from_folder;
 use std::{collections::BTreeMap, convert::TryInto, sync::Mutex};
@@ -37,6 +37,8 @@ pub const DEFAULT_LEAF: [u8; 32] = [
 #[allow(non_camel_case_types)]
 type VAnchorProver_Bn254_30_2_2_2 =
 	VAnchorR1CSProver<Bn254, TREE_DEPTH, ANCHOR_CT, NUM_UTXOS, NUM_UTXOS>;
+#[allow(non_camel_case_types)]
+type MixerProver_Bn254_30 = MixerR1CSProver<Bn254, TREE_DEPTH>;
 
 use crate::mock::*;
 
@@ -230,6 +232,47 @@ pub fn setup_zk_circuit(
 	(vanchor_proof.proof, pub_ins)
 }
 
+/// Creates a mixer note and a proof withdrawing it from a tree holding only that
+/// note, without fee or refund.
+pub fn setup_mixer_zk_circuit(
+	recipient_bytes: Vec<u8>,
+	relayer_bytes: Vec<u8>,
+	pk_bytes: Vec<u8>,
+) -> (
+	Vec<u8>, // proof bytes
+	Element, // root
+	Element, // nullifier_hash
+	Element, // leaf
+) {
+	let curve = Curve::Bn254;
+	let rng = &mut thread_rng();
+
+	let Leaf { secret_bytes, nullifier_bytes, leaf_bytes, nullifier_hash_bytes, .. } =
+		MixerProver_Bn254_30::create_random_leaf(curve, rng).unwrap();
+	let MixerProof { proof, root_raw, .. } = MixerProver_Bn254_30::create_proof(
+		curve,
+		secret_bytes,
+		nullifier_bytes,
+		vec![leaf_bytes.clone()],
+		0,
+		recipient_bytes,
+		relayer_bytes,
+		0,
+		0,
+		pk_bytes,
+		DEFAULT_LEAF,
+		rng,
+	)
+	.unwrap();
+
+	(
+		proof,
+		Element::from_bytes(&root_raw),
+		Element::from_bytes(&nullifier_hash_bytes),
+		Element::from_bytes(&leaf_bytes),
+	)
+}
+
 pub fn deconstruct_public_inputs(
 	public_inputs: &Vec<Bn254Fr>,
 ) -> (
@@ -1,21 +1,39 @@
 use crate::{
+	migrations::{SeedFlows, STORAGE_VERSION},
 	mock::*,
-	test_utils::{deconstruct_public_inputs_el, setup_utxos, setup_zk_circuit, TREE_DEPTH},
-	Error, Instance1, MaxDepositAmount, MinWithdrawAmount,
+	test_utils::{
+		deconstruct_public_inputs_el, setup_mixer_zk_circuit, setup_utxos, setup_zk_circuit,
+		TREE_DEPTH,
+	},
+	types::PendingWithdrawal,
+	CheckVAnchorTransact, Error, Flows, Instance1, MaxDepositAmount, MinWithdrawAmount,
+	NextPendingWithdrawalId, PendingWithdrawals,
 };
 use ark_ff::{BigInteger, PrimeField};
 use arkworks_setups::{common::setup_params, utxo::Utxo, Curve};
+use codec::Encode;
 use frame_benchmarking::account;
-use frame_support::{assert_err, assert_ok, traits::OnInitialize};
+use frame_support::{
+	assert_err, assert_ok,
+	dispatch::{DispatchInfo, GetDispatchInfo},
+	pallet_prelude::{
+		InvalidTransaction, TransactionSource, TransactionValidityError, ValidateUnsigned,
+	},
+	traits::{Get, GetStorageVersion, OnInitialize, OnRuntimeUpgrade, StorageVersion},
+};
 use orml_traits::MultiCurrency;
 use pallet_asset_registry::AssetType;
 use pallet_linkable_tree::LinkableTreeConfigration;
 use sp_core::hashing::keccak_256;
+use sp_runtime::{traits::SignedExtension, DispatchError};
 use std::convert::TryInto;
 use webb_primitives::{
 	linkable_tree::LinkableTreeInspector,
 	merkle_tree::TreeInspector,
-	types::vanchor::{ExtData, ProofData},
+	types::{
+		amount::I256,
+		vanchor::{ExtData, PauseStatus, ProofData, TreeFlows},
+	},
 	utils::compute_chain_id_type,
 	AccountId,
 };
@@ -143,10 +161,10 @@ fn create_vanchor_with_deposits(
 
 	let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 	let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-	let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+	let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 		recipient,
 		relayer,
-		ext_amount,
+		ext_amount.into(),
 		fee,
 		0,
 		asset_id.unwrap_or_default(),
@@ -192,6 +210,168 @@ fn create_vanchor_with_deposits(
 	(tree_id, out_utxos)
 }
 
+// Builds a withdrawal of `ext_amount` paying `fee` to the relayer, spending the
+// utxos created by `create_vanchor_with_deposits`.
+fn setup_withdraw_transaction(
+	proving_key_2x2_bytes: Vec<u8>,
+	tree_id: u32,
+	in_utxos: [Utxo<Bn254Fr>; 2],
+	ext_amount: Amount,
+	fee: Balance,
+) -> (ProofData<Element>, ExtData<AccountId, I256, Balance, AssetId>) {
+	let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+	setup_withdraw_transaction_to(
+		proving_key_2x2_bytes,
+		tree_id,
+		in_utxos,
+		recipient,
+		ext_amount,
+		fee,
+		0,
+		None,
+	)
+}
+
+// Builds a withdrawal of `ext_amount` of `token` to `recipient`, paying `fee` to
+// the relayer in `fee_token` if set.
+#[allow(clippy::too_many_arguments)]
+fn setup_withdraw_transaction_to(
+	proving_key_2x2_bytes: Vec<u8>,
+	tree_id: u32,
+	in_utxos: [Utxo<Bn254Fr>; 2],
+	recipient: AccountId,
+	ext_amount: Amount,
+	fee: Balance,
+	token: AssetId,
+	fee_token: Option<AssetId>,
+) -> (ProofData<Element>, ExtData<AccountId, I256, Balance, AssetId>) {
+	let custom_root = MerkleTree1::get_root(tree_id).unwrap();
+
+	let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+	let public_amount = ext_amount - fee as i128;
+
+	let chain_type = [2, 0];
+	let chain_id = compute_chain_id_type(ChainIdentifier::get(), chain_type);
+	let out_chain_ids = [chain_id; 2];
+	// The deposited utxos hold 10 in total
+	let out_amounts = [(10 + public_amount) as u128, 0];
+
+	let out_utxos = setup_utxos(out_chain_ids, out_amounts, None);
+
+	let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
+	let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
+	let mut ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
+		recipient,
+		relayer,
+		ext_amount.into(),
+		fee,
+		0,
+		token,
+		// Mock encryption value, not meant to be used in production
+		output1.to_vec(),
+		// Mock encryption value, not meant to be used in production
+		output2.to_vec(),
+	);
+	ext_data.fee_token = fee_token;
+
+	let ext_data_hash = keccak_256(&ext_data.encode_abi());
+
+	let neighbor_roots = <LinkableTree1 as LinkableTreeInspector<
+		LinkableTreeConfigration<Test, Instance1>,
+	>>::get_neighbor_roots(tree_id)
+	.unwrap()
+	.try_into()
+	.unwrap();
+
+	let (proof, public_inputs) = setup_zk_circuit(
+		public_amount,
+		chain_id,
+		ext_data_hash.to_vec(),
+		in_utxos,
+		out_utxos,
+		proving_key_2x2_bytes,
+		neighbor_roots,
+		custom_root,
+	);
+
+	// Deconstructing public inputs
+	let (_chain_id, public_amount, root_set, nullifiers, commitments, ext_data_hash) =
+		deconstruct_public_inputs_el(&public_inputs);
+
+	// Constructing proof data
+	let proof_data =
+		ProofData::new(proof, public_amount, root_set, nullifiers, commitments, ext_data_hash);
+
+	(proof_data, ext_data)
+}
+
+// Builds a deposit of `ext_amount` of `token` into an empty tree.
+fn setup_deposit_transaction(
+	proving_key_2x2_bytes: Vec<u8>,
+	tree_id: u32,
+	ext_amount: Amount,
+	token: AssetId,
+) -> (ProofData<Element>, ExtData<AccountId, I256, Balance, AssetId>) {
+	let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+	let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+
+	let chain_type = [2, 0];
+	let chain_id = compute_chain_id_type(ChainIdentifier::get(), chain_type);
+	let in_chain_ids = [chain_id; 2];
+	let in_amounts = [0, 0];
+	let in_indices = [0, 1];
+	let out_chain_ids = [chain_id; 2];
+	let out_amounts = [ext_amount as u128, 0];
+
+	let in_utxos = setup_utxos(in_chain_ids, in_amounts, Some(in_indices));
+	let out_utxos = setup_utxos(out_chain_ids, out_amounts, None);
+
+	let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
+	let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
+	let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
+		recipient,
+		relayer,
+		ext_amount.into(),
+		0,
+		0,
+		token,
+		// Mock encryption value, not meant to be used in production
+		output1.to_vec(),
+		// Mock encryption value, not meant to be used in production
+		output2.to_vec(),
+	);
+
+	let ext_data_hash = keccak_256(&ext_data.encode_abi());
+
+	let custom_root = MerkleTree1::get_default_root(tree_id).unwrap();
+	let neighbor_roots: [Element; EDGE_CT] = <LinkableTree1 as LinkableTreeInspector<
+		LinkableTreeConfigration<Test, Instance1>,
+	>>::get_neighbor_roots(tree_id)
+	.unwrap()
+	.try_into()
+	.unwrap();
+	let (proof, public_inputs) = setup_zk_circuit(
+		ext_amount,
+		chain_id,
+		ext_data_hash.to_vec(),
+		in_utxos,
+		out_utxos,
+		proving_key_2x2_bytes,
+		neighbor_roots,
+		custom_root,
+	);
+
+	// Deconstructing public inputs
+	let (_chain_id, public_amount, root_set, nullifiers, commitments, ext_data_hash) =
+		deconstruct_public_inputs_el(&public_inputs);
+
+	// Constructing proof data
+	let proof_data =
+		ProofData::new(proof, public_amount, root_set, nullifiers, commitments, ext_data_hash);
+
+	(proof_data, ext_data)
+}
+
 #[test]
 fn should_complete_2x2_transaction_with_deposit() {
 	new_test_ext().execute_with(|| {
@@ -219,10 +399,10 @@ fn should_complete_2x2_transaction_with_deposit() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -260,10 +440,10 @@ fn should_complete_2x2_transaction_with_deposit() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -325,10 +505,10 @@ fn should_complete_2x2_transaction_with_withdraw() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -363,10 +543,10 @@ fn should_complete_2x2_transaction_with_withdraw() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -444,10 +624,10 @@ fn should_complete_2x2_transaction_with_withdraw_unwrap_and_refund_native_token(
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			refund,
 			0,
@@ -509,10 +689,10 @@ fn should_complete_2x2_transaction_with_withdraw_unwrap_and_refund_native_token(
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			refund,
 			NativeCurrencyId::get(),
@@ -627,10 +807,10 @@ fn should_complete_2x2_transaction_with_withdraw_unwrap_and_refund_non_native_to
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			refund,
 			first_asset_id,
@@ -687,10 +867,10 @@ fn should_complete_2x2_transaction_with_withdraw_unwrap_and_refund_non_native_to
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			refund,
 			first_asset_id,
@@ -725,10 +905,10 @@ fn should_complete_2x2_transaction_with_withdraw_unwrap_and_refund_non_native_to
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			refund,
 			first_asset_id,
@@ -808,10 +988,10 @@ fn should_complete_register_and_transact() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -846,10 +1026,10 @@ fn should_complete_register_and_transact() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -909,10 +1089,10 @@ fn should_not_complete_transaction_if_ext_data_is_invalid() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -952,10 +1132,10 @@ fn should_not_complete_transaction_if_ext_data_is_invalid() {
 
 		// INVALID output commitment
 		let output2 = Element::from_bytes(&[0u8; 32]);
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1021,10 +1201,10 @@ fn should_not_complete_withdraw_if_out_amount_sum_is_too_big() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1061,10 +1241,10 @@ fn should_not_complete_withdraw_if_out_amount_sum_is_too_big() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1132,10 +1312,10 @@ fn should_not_complete_withdraw_if_out_amount_sum_is_too_small() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1172,10 +1352,10 @@ fn should_not_complete_withdraw_if_out_amount_sum_is_too_small() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1240,10 +1420,10 @@ fn should_not_be_able_to_double_spend() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1280,10 +1460,10 @@ fn should_not_be_able_to_double_spend() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1355,10 +1535,10 @@ fn should_not_be_able_to_exceed_max_fee() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1396,10 +1576,10 @@ fn should_not_be_able_to_exceed_max_fee() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient,
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1461,10 +1641,10 @@ fn should_not_be_able_to_exceed_max_deposit() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1502,10 +1682,10 @@ fn should_not_be_able_to_exceed_max_deposit() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient,
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1567,10 +1747,10 @@ fn should_not_be_able_to_exceed_external_amount() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1608,10 +1788,10 @@ fn should_not_be_able_to_exceed_external_amount() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient,
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1670,10 +1850,10 @@ fn should_not_be_able_to_withdraw_less_than_minimum() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1708,10 +1888,10 @@ fn should_not_be_able_to_withdraw_less_than_minimum() {
 		// Constructing external data
 		let output1 = commitments[0];
 		let output2 = commitments[1];
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -1783,3 +1963,943 @@ fn should_fail_to_set_amounts_with_invalid_nonces() {
 		);
 	})
 }
+
+// The pool code of an error of this pallet, mapped as `CheckVAnchorTransact` does
+fn pool_error(error: Error<Test, Instance1>) -> TransactionValidityError {
+	InvalidTransaction::Custom(error.encode()[0]).into()
+}
+
+#[test]
+fn should_validate_transact_in_pool_and_tag_nullifiers() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		let call: RuntimeCall = crate::Call::<Test, Instance1>::transact {
+			id: tree_id,
+			proof_data: proof_data.clone(),
+			ext_data: ext_data.clone(),
+		}
+		.into();
+		let info = DispatchInfo::default();
+
+		let valid = CheckVAnchorTransact::<Test, Instance1>::new()
+			.validate(&transactor, &call, &info, 0)
+			.unwrap();
+		assert_eq!(valid.provides.len(), proof_data.input_nullifiers.len());
+
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor.clone()),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+
+		// The nullifiers are now spent, so the pool must reject the transaction
+		assert_eq!(
+			CheckVAnchorTransact::<Test, Instance1>::new().validate(&transactor, &call, &info, 0),
+			Err(pool_error(Error::<Test, Instance1>::AlreadyRevealedNullifier))
+		);
+	});
+}
+
+#[test]
+fn should_reject_transact_with_invalid_ext_data_in_pool() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, mut ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+		// Tamper with the recipient so that the ext data hash no longer matches
+		ext_data.recipient = get_account(BIG_TRANSACTOR_ACCOUNT_ID);
+
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		let call: RuntimeCall =
+			crate::Call::<Test, Instance1>::transact { id: tree_id, proof_data, ext_data }.into();
+
+		assert_eq!(
+			CheckVAnchorTransact::<Test, Instance1>::new().validate(
+				&transactor,
+				&call,
+				&DispatchInfo::default(),
+				0
+			),
+			Err(pool_error(Error::<Test, Instance1>::InvalidExtData))
+		);
+	});
+}
+
+#[test]
+fn should_complete_unsigned_withdraw_and_pay_fee_to_block_author() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let ext_amount: Amount = -5;
+		let fee: Balance = 2;
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, ext_amount, fee);
+
+		let call = crate::Call::<Test, Instance1>::transact_unsigned {
+			id: tree_id,
+			proof_data: proof_data.clone(),
+			ext_data: ext_data.clone(),
+		};
+		let valid =
+			<VAnchor1 as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call)
+				.unwrap();
+		// the fee raises the priority in multiples of the min fee, up to a cap
+		assert_eq!(
+			valid.priority,
+			UnsignedPriority::get() + fee as u64 / MinUnsignedFee::get() as u64
+		);
+		assert_eq!(
+			VAnchor1::unsigned_transact_priority(Balance::MAX),
+			UnsignedPriority::get() + MaxUnsignedFeePriority::get()
+		);
+		assert_eq!(valid.longevity, crate::UNSIGNED_TRANSACT_LONGEVITY);
+		assert_eq!(valid.provides.len(), proof_data.input_nullifiers.len());
+
+		let author = BlockAuthor::get().unwrap();
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+		let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+		let author_balance_before = Balances::free_balance(author.clone());
+		let recipient_balance_before = Balances::free_balance(recipient.clone());
+		let relayer_balance_before = Balances::free_balance(relayer.clone());
+		assert_ok!(VAnchor1::transact_unsigned(
+			RuntimeOrigin::none(),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+
+		// The fee goes to the block author, not the relayer
+		assert_eq!(Balances::free_balance(author), author_balance_before + fee);
+		assert_eq!(Balances::free_balance(relayer), relayer_balance_before);
+		assert_eq!(
+			Balances::free_balance(recipient),
+			recipient_balance_before + ext_amount.unsigned_abs()
+		);
+	});
+}
+
+#[test]
+fn should_not_allow_unsigned_deposits_or_zero_fees() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+
+		let mut deposit_ext_data = ext_data.clone();
+		deposit_ext_data.ext_amount = 5u128.into();
+		let call = crate::Call::<Test, Instance1>::transact_unsigned {
+			id: tree_id,
+			proof_data: proof_data.clone(),
+			ext_data: deposit_ext_data.clone(),
+		};
+		assert_eq!(
+			<VAnchor1 as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_err!(
+			VAnchor1::transact_unsigned(
+				RuntimeOrigin::none(),
+				tree_id,
+				proof_data.clone(),
+				deposit_ext_data
+			),
+			Error::<Test, Instance1>::InvalidUnsignedTransaction
+		);
+
+		// a note spent twice is rejected before the proof is verified
+		let mut double_spend_proof_data = proof_data.clone();
+		double_spend_proof_data.input_nullifiers[1] = double_spend_proof_data.input_nullifiers[0];
+		let call = crate::Call::<Test, Instance1>::transact_unsigned {
+			id: tree_id,
+			proof_data: double_spend_proof_data,
+			ext_data: ext_data.clone(),
+		};
+		assert_eq!(
+			<VAnchor1 as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Stale.into())
+		);
+
+		let mut free_ext_data = ext_data;
+		free_ext_data.fee = 0;
+		let call = crate::Call::<Test, Instance1>::transact_unsigned {
+			id: tree_id,
+			proof_data,
+			ext_data: free_ext_data,
+		};
+		assert_eq!(
+			<VAnchor1 as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Payment.into())
+		);
+	});
+}
+
+#[test]
+fn should_limit_unsigned_transactions_per_block() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+		let call =
+			crate::Call::<Test, Instance1>::transact_unsigned { id: tree_id, proof_data, ext_data };
+
+		for _ in 0..MaxUnsignedTransactsPerBlock::get() {
+			assert_ok!(<VAnchor1 as ValidateUnsigned>::pre_dispatch(&call));
+		}
+		assert_eq!(
+			<VAnchor1 as ValidateUnsigned>::pre_dispatch(&call),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		// The counter is reset at the start of every block
+		<VAnchor1 as OnInitialize<u64>>::on_initialize(2);
+		assert_ok!(<VAnchor1 as ValidateUnsigned>::pre_dispatch(&call));
+	});
+}
+
+#[test]
+fn should_reject_oversized_encrypted_outputs() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, mut ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+
+		let call = crate::Call::<Test, Instance1>::transact {
+			id: tree_id,
+			proof_data: proof_data.clone(),
+			ext_data: ext_data.clone(),
+		};
+		let weight = call.get_dispatch_info().weight;
+
+		ext_data.encrypted_output1 = vec![0u8; MaxEncryptedOutputLength::get() as usize + 1];
+		let oversized_call = crate::Call::<Test, Instance1>::transact {
+			id: tree_id,
+			proof_data: proof_data.clone(),
+			ext_data: ext_data.clone(),
+		};
+		// Longer payloads are charged more
+		assert!(oversized_call.get_dispatch_info().weight.ref_time() > weight.ref_time());
+
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		assert_err!(
+			VAnchor1::transact(RuntimeOrigin::signed(transactor), tree_id, proof_data, ext_data),
+			Error::<Test, Instance1>::EncryptedOutputTooLong
+		);
+	});
+}
+
+#[test]
+fn should_reject_malformed_encrypted_output_envelopes() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+
+		// The mock outputs are raw commitments rather than envelopes
+		RequireEncryptedOutputEnvelope::set(true);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				proof_data.clone(),
+				ext_data.clone()
+			),
+			Error::<Test, Instance1>::MalformedEncryptedOutput
+		);
+
+		RequireEncryptedOutputEnvelope::set(false);
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+	});
+}
+
+#[test]
+fn should_block_transactions_of_paused_vanchor() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (deposit_proof_data, deposit_ext_data) = setup_withdraw_transaction(
+			proving_key_2x2_bytes.clone(),
+			tree_id,
+			in_utxos.clone(),
+			5,
+			0,
+		);
+		let (withdraw_proof_data, withdraw_ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+
+		// Only root can pause a vanchor
+		assert_err!(
+			VAnchor1::set_pause_status(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				Some(PauseStatus::Deposits),
+				VAnchor1::proposal_nonce() + 1
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(VAnchor1::set_pause_status(
+			RuntimeOrigin::root(),
+			tree_id,
+			Some(PauseStatus::All),
+			VAnchor1::proposal_nonce() + 1
+		));
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				withdraw_proof_data.clone(),
+				withdraw_ext_data.clone()
+			),
+			Error::<Test, Instance1>::VAnchorPaused
+		);
+
+		// Pausing only deposits lets funds be withdrawn
+		assert_ok!(VAnchor1::set_pause_status(
+			RuntimeOrigin::root(),
+			tree_id,
+			Some(PauseStatus::Deposits),
+			VAnchor1::proposal_nonce() + 1
+		));
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				deposit_proof_data.clone(),
+				deposit_ext_data.clone()
+			),
+			Error::<Test, Instance1>::DepositsPaused
+		);
+		assert_ok!(VAnchor1::validate_transact(tree_id, &withdraw_proof_data, &withdraw_ext_data));
+
+		assert_ok!(VAnchor1::set_pause_status(
+			RuntimeOrigin::root(),
+			tree_id,
+			None,
+			VAnchor1::proposal_nonce() + 1
+		));
+		assert_eq!(VAnchor1::pause_status(tree_id), None);
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor),
+			tree_id,
+			deposit_proof_data,
+			deposit_ext_data
+		));
+	});
+}
+
+#[test]
+fn should_hold_back_withdrawals_over_the_withdrawal_limit() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+
+		assert_eq!(VAnchor1::remaining_withdrawal_capacity(tree_id), None);
+		assert_ok!(VAnchor1::set_withdrawal_limit(RuntimeOrigin::root(), tree_id, Some(4)));
+		assert_eq!(VAnchor1::remaining_withdrawal_capacity(tree_id), Some(4));
+
+		// The transaction succeeds but the withdrawal is held back
+		let recipient_balance_before = Balances::free_balance(recipient.clone());
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+		assert_eq!(Balances::free_balance(recipient.clone()), recipient_balance_before);
+		let release_at = System::block_number() + WithdrawalReleaseDelay::get();
+		System::assert_last_event(
+			crate::Event::<Test, Instance1>::WithdrawalLimitExceeded {
+				tree_id,
+				withdrawal_id: 0,
+				amount: 5,
+				release_at,
+			}
+			.into(),
+		);
+		assert_eq!(VAnchor1::remaining_withdrawal_capacity(tree_id), Some(4));
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 10, withdrawn: 0, fees_paid: 0, locked: 5, held_back: 5 }
+		);
+		assert!(VAnchor1::is_solvent());
+
+		let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+		assert_err!(
+			VAnchor1::release_withdrawal(RuntimeOrigin::signed(relayer.clone()), 0),
+			Error::<Test, Instance1>::WithdrawalNotReleasable
+		);
+
+		System::set_block_number(release_at);
+		assert_ok!(VAnchor1::release_withdrawal(RuntimeOrigin::signed(relayer.clone()), 0));
+		assert_eq!(Balances::free_balance(recipient), recipient_balance_before + 5);
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 10, withdrawn: 5, fees_paid: 0, locked: 5, held_back: 0 }
+		);
+		assert_err!(
+			VAnchor1::release_withdrawal(RuntimeOrigin::signed(relayer), 0),
+			Error::<Test, Instance1>::PendingWithdrawalNotFound
+		);
+	});
+}
+
+#[test]
+fn should_track_withdrawal_capacity_and_cancel_held_back_withdrawals() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+
+		assert_ok!(VAnchor1::set_withdrawal_limit(RuntimeOrigin::root(), tree_id, Some(8)));
+		let recipient_balance_before = Balances::free_balance(recipient.clone());
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor.clone()),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+		assert_eq!(Balances::free_balance(recipient.clone()), recipient_balance_before + 5);
+		assert_eq!(VAnchor1::remaining_withdrawal_capacity(tree_id), Some(3));
+
+		// The capacity is restored once the window has passed
+		System::set_block_number(System::block_number() + WithdrawalWindowLength::get());
+		assert_eq!(VAnchor1::remaining_withdrawal_capacity(tree_id), Some(8));
+
+		PendingWithdrawals::<Test, Instance1>::insert(
+			0,
+			PendingWithdrawal {
+				tree_id,
+				recipient: recipient.clone(),
+				token: 0,
+				amount: 5,
+				release_at: System::block_number(),
+			},
+		);
+		Flows::<Test, Instance1>::mutate(tree_id, |flows| {
+			flows.locked -= 5;
+			flows.held_back += 5;
+		});
+		let refund_to: AccountId = get_account(RELAYER_ACCOUNT_ID);
+		assert_err!(
+			VAnchor1::cancel_withdrawal(RuntimeOrigin::signed(transactor), 0, refund_to.clone()),
+			DispatchError::BadOrigin
+		);
+
+		// The held back funds are paid to the refund account, not the recipient
+		let recipient_balance_before = Balances::free_balance(recipient.clone());
+		let refund_balance_before = Balances::free_balance(refund_to.clone());
+		assert_ok!(VAnchor1::cancel_withdrawal(RuntimeOrigin::root(), 0, refund_to.clone()));
+		assert!(VAnchor1::pending_withdrawals(0).is_none());
+		assert_eq!(Balances::free_balance(recipient), recipient_balance_before);
+		assert_eq!(Balances::free_balance(refund_to.clone()), refund_balance_before + 5);
+		assert_eq!(VAnchor1::tree_flows(tree_id).held_back, 0);
+		System::assert_last_event(
+			crate::Event::<Test, Instance1>::WithdrawalCancelled { withdrawal_id: 0, refund_to }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn should_fail_to_hold_back_withdrawals_when_out_of_ids() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 0);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+
+		assert_ok!(VAnchor1::set_withdrawal_limit(RuntimeOrigin::root(), tree_id, Some(4)));
+		NextPendingWithdrawalId::<Test, Instance1>::put(u32::MAX);
+		assert_err!(
+			VAnchor1::transact(RuntimeOrigin::signed(transactor), tree_id, proof_data, ext_data),
+			Error::<Test, Instance1>::PendingWithdrawalIdOverflow
+		);
+		assert!(VAnchor1::pending_withdrawals(u32::MAX).is_none());
+	});
+}
+
+#[test]
+fn should_track_tree_flows_and_solvency() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 10, withdrawn: 0, fees_paid: 0, locked: 10, held_back: 0 }
+		);
+
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 10, withdrawn: 5, fees_paid: 2, locked: 3, held_back: 0 }
+		);
+
+		let solvency = VAnchor1::solvency();
+		assert_eq!(solvency.len(), 1);
+		assert_eq!(solvency[0].asset, 0);
+		assert_eq!(solvency[0].locked, 3);
+		let held = Balances::free_balance(VAnchor1::account_id());
+		assert_eq!(solvency[0].held, held);
+		assert_eq!(solvency[0].surplus, held - 3);
+		assert_eq!(solvency[0].shortfall, 0);
+		assert!(VAnchor1::is_solvent());
+
+		// Draining the pallet account makes the vanchors insolvent
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), VAnchor1::account_id(), 0, 0));
+		let solvency = VAnchor1::solvency();
+		assert_eq!(solvency[0].surplus, 0);
+		assert_eq!(solvency[0].shortfall, 3);
+		assert!(!VAnchor1::is_solvent());
+	});
+}
+
+#[test]
+fn should_seed_tree_flows_from_pallet_balances_on_upgrade() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (tree_id, _) = create_vanchor_with_deposits(proving_key_2x2_bytes, None);
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+		PendingWithdrawals::<Test, Instance1>::insert(
+			0,
+			PendingWithdrawal {
+				tree_id,
+				recipient,
+				token: 0,
+				amount: 4,
+				release_at: System::block_number(),
+			},
+		);
+
+		// Forget the flows, as on a chain upgraded from before they were tracked
+		Flows::<Test, Instance1>::remove(tree_id);
+		StorageVersion::new(0).put::<VAnchor1>();
+		SeedFlows::<Test, Instance1>::on_runtime_upgrade();
+
+		let held = Balances::free_balance(VAnchor1::account_id());
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 0, withdrawn: 0, fees_paid: 0, locked: held - 4, held_back: 4 }
+		);
+		assert!(VAnchor1::is_solvent());
+		assert_eq!(VAnchor1::on_chain_storage_version(), STORAGE_VERSION);
+
+		// The migration only runs once
+		SeedFlows::<Test, Instance1>::on_runtime_upgrade();
+		assert_eq!(VAnchor1::tree_flows(tree_id).held_back, 4);
+	});
+}
+
+// Registers a pool share wrapping the native asset, funding the transactor with
+// some of it, and creates a vanchor of it with 10 deposited.
+fn create_wrapped_vanchor_with_deposits(
+	proving_key_2x2_bytes: Vec<u8>,
+) -> (AssetId, u32, [Utxo<Bn254Fr>; 2]) {
+	assert_ok!(AssetRegistry::register(
+		RuntimeOrigin::root(),
+		b"webbWEBB".to_vec().try_into().unwrap(),
+		AssetType::PoolShare(vec![0].try_into().unwrap()),
+		0
+	));
+	let asset_id = AssetRegistry::next_asset_id() - 1;
+	let alice = get_account(TRANSACTOR_ACCOUNT_ID);
+	assert_ok!(TokenWrapper::wrap(
+		RuntimeOrigin::signed(alice.clone()),
+		NativeCurrencyId::get(),
+		asset_id,
+		1_000,
+		alice,
+	));
+	let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes, Some(asset_id));
+	(asset_id, tree_id, in_utxos)
+}
+
+#[test]
+fn should_pay_relayer_fee_in_unwrapped_fee_token() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (asset_id, tree_id, in_utxos) =
+			create_wrapped_vanchor_with_deposits(proving_key_2x2_bytes.clone());
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+		let (proof_data, ext_data) = setup_withdraw_transaction_to(
+			proving_key_2x2_bytes,
+			tree_id,
+			in_utxos,
+			recipient.clone(),
+			-5,
+			2,
+			asset_id,
+			Some(NativeCurrencyId::get()),
+		);
+
+		let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+		let recipient_balance_before = Currencies::free_balance(asset_id, &recipient);
+		let relayer_balance_before = Balances::free_balance(relayer.clone());
+		let relayer_wrapped_balance_before = Currencies::free_balance(asset_id, &relayer);
+
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(get_account(TRANSACTOR_ACCOUNT_ID)),
+			tree_id,
+			proof_data,
+			ext_data,
+		));
+
+		// The fee is paid in the native token, the withdrawal in the wrapped token
+		assert_eq!(Balances::free_balance(relayer.clone()), relayer_balance_before + 2);
+		assert_eq!(Currencies::free_balance(asset_id, &relayer), relayer_wrapped_balance_before);
+		assert_eq!(Currencies::free_balance(asset_id, &recipient), recipient_balance_before + 5);
+	});
+}
+
+#[test]
+fn should_bind_fee_token_to_ext_data_hash() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (asset_id, tree_id, in_utxos) =
+			create_wrapped_vanchor_with_deposits(proving_key_2x2_bytes.clone());
+		let (proof_data, ext_data) = setup_withdraw_transaction_to(
+			proving_key_2x2_bytes,
+			tree_id,
+			in_utxos,
+			get_account(RECIPIENT_ACCOUNT_ID),
+			-5,
+			2,
+			asset_id,
+			Some(NativeCurrencyId::get()),
+		);
+		let transactor = get_account(TRANSACTOR_ACCOUNT_ID);
+
+		// The proof does not cover a plain transaction, nor another fee token
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				proof_data.clone(),
+				ExtData { fee_token: None, ..ext_data.clone() }
+			),
+			Error::<Test, Instance1>::InvalidExtData
+		);
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor),
+				tree_id,
+				proof_data,
+				ext_data.with_fee_token(asset_id),
+			),
+			Error::<Test, Instance1>::InvalidExtData
+		);
+	});
+}
+
+#[test]
+fn should_reject_fee_token_outside_the_wrapper_pool() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let (asset_id, tree_id, in_utxos) =
+			create_wrapped_vanchor_with_deposits(proving_key_2x2_bytes.clone());
+		// An asset that is registered but not wrapped into the vanchor asset
+		assert_ok!(AssetRegistry::register(
+			RuntimeOrigin::root(),
+			b"other".to_vec().try_into().unwrap(),
+			AssetType::Token,
+			0
+		));
+		let other_asset_id = AssetRegistry::next_asset_id() - 1;
+		let (proof_data, ext_data) = setup_withdraw_transaction_to(
+			proving_key_2x2_bytes,
+			tree_id,
+			in_utxos,
+			get_account(RECIPIENT_ACCOUNT_ID),
+			-5,
+			2,
+			asset_id,
+			Some(other_asset_id),
+		);
+
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(get_account(TRANSACTOR_ACCOUNT_ID)),
+				tree_id,
+				proof_data,
+				ext_data,
+			),
+			Error::<Test, Instance1>::InvalidFeeToken
+		);
+	});
+}
+
+#[test]
+fn should_swap_between_vanchors_of_different_assets() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		// Register a new wrapped asset / pool share over native assets
+		assert_ok!(AssetRegistry::register(
+			RuntimeOrigin::root(),
+			b"webbWEBB".to_vec().try_into().unwrap(),
+			AssetType::PoolShare(vec![0].try_into().unwrap()),
+			0
+		));
+		let asset_id = AssetRegistry::next_asset_id() - 1;
+
+		// Swap 5 out of the native vanchor into the wrapped vanchor
+		let (from_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let to_id = create_vanchor(asset_id);
+		let (from_proof_data, from_ext_data) = setup_withdraw_transaction_to(
+			proving_key_2x2_bytes.clone(),
+			from_id,
+			in_utxos,
+			VAnchor1::account_id(),
+			-5,
+			0,
+			0,
+			None,
+		);
+		let (to_proof_data, to_ext_data) =
+			setup_deposit_transaction(proving_key_2x2_bytes, to_id, 5, NativeCurrencyId::get());
+
+		let relayer = get_account(RELAYER_ACCOUNT_ID);
+		let relayer_balance_before = Balances::free_balance(relayer.clone());
+
+		// The withdrawn value cannot leave the pallet
+		let recipient: AccountId = get_account(RECIPIENT_ACCOUNT_ID);
+		assert_err!(
+			VAnchor1::swap(
+				RuntimeOrigin::signed(relayer.clone()),
+				from_id,
+				from_proof_data.clone(),
+				ExtData { recipient, ..from_ext_data.clone() },
+				to_id,
+				to_proof_data.clone(),
+				to_ext_data.clone(),
+			),
+			Error::<Test, Instance1>::InvalidSwap
+		);
+
+		assert_ok!(VAnchor1::swap(
+			RuntimeOrigin::signed(relayer.clone()),
+			from_id,
+			from_proof_data,
+			from_ext_data,
+			to_id,
+			to_proof_data,
+			to_ext_data,
+		));
+
+		// The relayer paid nothing and the value moved between the vanchors
+		assert_eq!(Balances::free_balance(relayer), relayer_balance_before);
+		assert_eq!(
+			VAnchor1::tree_flows(from_id),
+			TreeFlows { deposited: 10, withdrawn: 5, fees_paid: 0, locked: 5, held_back: 0 }
+		);
+		assert_eq!(
+			VAnchor1::tree_flows(to_id),
+			TreeFlows { deposited: 5, withdrawn: 0, fees_paid: 0, locked: 5, held_back: 0 }
+		);
+		assert_eq!(Currencies::free_balance(asset_id, &VAnchor1::account_id()), 5);
+		assert!(VAnchor1::is_solvent());
+	});
+}
+
+#[test]
+fn should_not_swap_withdrawal_of_another_relayer() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		assert_ok!(AssetRegistry::register(
+			RuntimeOrigin::root(),
+			b"webbWEBB".to_vec().try_into().unwrap(),
+			AssetType::PoolShare(vec![0].try_into().unwrap()),
+			0
+		));
+		let asset_id = AssetRegistry::next_asset_id() - 1;
+
+		let (from_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let to_id = create_vanchor(asset_id);
+		// The withdrawal names RELAYER_ACCOUNT_ID as its relayer
+		let (from_proof_data, from_ext_data) = setup_withdraw_transaction_to(
+			proving_key_2x2_bytes.clone(),
+			from_id,
+			in_utxos,
+			VAnchor1::account_id(),
+			-5,
+			0,
+			0,
+			None,
+		);
+		let (to_proof_data, to_ext_data) =
+			setup_deposit_transaction(proving_key_2x2_bytes, to_id, 5, NativeCurrencyId::get());
+
+		// A third party seeing the withdrawal cannot pair it with their own deposit
+		assert_err!(
+			VAnchor1::swap(
+				RuntimeOrigin::signed(get_account(TRANSACTOR_ACCOUNT_ID)),
+				from_id,
+				from_proof_data.clone(),
+				from_ext_data,
+				to_id,
+				to_proof_data,
+				to_ext_data,
+			),
+			Error::<Test, Instance1>::InvalidSwap
+		);
+		assert!(!VAnchor1::nullifier_hashes(from_id, from_proof_data.input_nullifiers[0]));
+	});
+}
+
+#[test]
+fn should_reject_transactions_refused_by_transact_filter() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		let relayer: AccountId = get_account(RELAYER_ACCOUNT_ID);
+
+		// Denied depositors cannot deposit
+		let tree_id = create_vanchor(0);
+		let (proof_data, ext_data) =
+			setup_deposit_transaction(proving_key_2x2_bytes.clone(), tree_id, 10, 0);
+		DeniedAccount::set(Some(transactor.clone()));
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				proof_data,
+				ext_data
+			),
+			DispatchError::Other("Denied account")
+		);
+		DeniedAccount::set(None);
+
+		// Denied relayers cannot take part in withdrawals
+		let (tree_id, in_utxos) = create_vanchor_with_deposits(proving_key_2x2_bytes.clone(), None);
+		let (proof_data, ext_data) =
+			setup_withdraw_transaction(proving_key_2x2_bytes, tree_id, in_utxos, -5, 2);
+		DeniedAccount::set(Some(relayer));
+		assert_err!(
+			VAnchor1::transact(
+				RuntimeOrigin::signed(transactor.clone()),
+				tree_id,
+				proof_data.clone(),
+				ext_data.clone()
+			),
+			DispatchError::Other("Denied account")
+		);
+		assert!(!VAnchor1::nullifier_hashes(tree_id, proof_data.input_nullifiers[0]));
+		DeniedAccount::set(None);
+		assert_ok!(VAnchor1::transact(
+			RuntimeOrigin::signed(transactor),
+			tree_id,
+			proof_data,
+			ext_data
+		));
+	});
+}
+
+#[test]
+fn should_migrate_mixer_note_into_vanchor() {
+	new_test_ext().execute_with(|| {
+		let (proving_key_2x2_bytes, _, _, _) = setup_environment();
+		let mixer_pk_bytes = include_bytes!(
+			"../../../substrate-fixtures/mixer/bn254/x5/proving_key_uncompressed.bin"
+		)
+		.to_vec();
+		let mixer_vk_bytes =
+			include_bytes!("../../../substrate-fixtures/mixer/bn254/x5/verifying_key.bin").to_vec();
+		assert_ok!(MixerVerifier::force_set_parameters(
+			RuntimeOrigin::root(),
+			mixer_vk_bytes.try_into().unwrap()
+		));
+
+		// Deposit a note of 10 into a native mixer
+		let transactor: AccountId = get_account(TRANSACTOR_ACCOUNT_ID);
+		assert_ok!(Mixer1::create(RuntimeOrigin::root(), 10, TREE_DEPTH as u8, 0));
+		let mixer_id = MerkleTree1::next_tree_id() - 1;
+		let (mixer_proof, mixer_root, mixer_nullifier_hash, leaf) = setup_mixer_zk_circuit(
+			pallet_mixer::truncate_and_pad(&VAnchor1::account_id().encode()),
+			pallet_mixer::truncate_and_pad(&transactor.encode()),
+			mixer_pk_bytes,
+		);
+		assert_ok!(Mixer1::deposit(RuntimeOrigin::signed(transactor.clone()), mixer_id, leaf));
+
+		let tree_id = create_vanchor(0);
+		let (proof_data, ext_data) =
+			setup_deposit_transaction(proving_key_2x2_bytes, tree_id, 10, 0);
+		let transactor_balance_before = Balances::free_balance(transactor.clone());
+
+		// The deposit has to be exactly the mixer note
+		assert_err!(
+			VAnchor1::migrate_from_mixer(
+				RuntimeOrigin::signed(transactor.clone()),
+				mixer_id,
+				mixer_proof.clone(),
+				mixer_root,
+				mixer_nullifier_hash,
+				tree_id,
+				proof_data.clone(),
+				ExtData { ext_amount: 5u128.into(), ..ext_data.clone() },
+			),
+			Error::<Test, Instance1>::InvalidMixerMigration
+		);
+
+		assert_ok!(VAnchor1::migrate_from_mixer(
+			RuntimeOrigin::signed(transactor.clone()),
+			mixer_id,
+			mixer_proof,
+			mixer_root,
+			mixer_nullifier_hash,
+			tree_id,
+			proof_data,
+			ext_data,
+		));
+
+		// The note moved from the mixer into the vanchor without paying anyone
+		assert_eq!(Balances::free_balance(transactor.clone()), transactor_balance_before);
+		assert_eq!(Balances::free_balance(Mixer1::account_id()), 0);
+		assert!(Mixer1::nullifier_hashes(mixer_id, mixer_nullifier_hash));
+		assert_eq!(
+			VAnchor1::tree_flows(tree_id),
+			TreeFlows { deposited: 10, withdrawn: 0, fees_paid: 0, locked: 10, held_back: 0 }
+		);
+		assert!(VAnchor1::is_solvent());
+		System::assert_last_event(
+			crate::Event::<Test, Instance1>::MixerMigration {
+				transactor,
+				mixer_id,
+				tree_id,
+				amount: 10,
+			}
+			.into(),
+		);
+	});
+}
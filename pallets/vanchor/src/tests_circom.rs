@@ -30,7 +30,10 @@ use sp_core::hashing::keccak_256;
 use webb_primitives::{
 	linkable_tree::LinkableTreeInspector,
 	merkle_tree::TreeInspector,
-	types::vanchor::{ExtData, ProofData},
+	types::{
+		amount::I256,
+		vanchor::{ExtData, ProofData},
+	},
 	utils::compute_chain_id_type,
 	verifying::circom::Proof as SolidityProof,
 	AccountId,
@@ -128,10 +131,10 @@ fn circom_should_complete_2x2_transaction_with_withdraw() {
 
 		let output1 = out_utxos[0].commitment.into_repr().to_bytes_be();
 		let output2 = out_utxos[1].commitment.into_repr().to_bytes_be();
-		let ext_data = ExtData::<AccountId, Amount, Balance, AssetId>::new(
+		let ext_data = ExtData::<AccountId, I256, Balance, AssetId>::new(
 			recipient.clone(),
 			relayer.clone(),
-			ext_amount,
+			ext_amount.into(),
 			fee,
 			0,
 			0,
@@ -0,0 +1,27 @@
+//! Types used by the VAnchor pallet
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Withdrawal volume of a vanchor within the current window of blocks
+#[derive(Clone, Encode, Decode, Debug, Default, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct WithdrawalWindow<Balance, BlockNumber> {
+	/// Block at which the window started
+	pub start: BlockNumber,
+	/// Amount withdrawn since the window started
+	pub withdrawn: Balance,
+}
+
+/// A withdrawal held back because it exceeded the withdrawal limit of its vanchor
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct PendingWithdrawal<TreeId, AccountId, CurrencyId, Balance, BlockNumber> {
+	/// Tree the withdrawal was made from
+	pub tree_id: TreeId,
+	/// Account receiving the withdrawal
+	pub recipient: AccountId,
+	/// Token requested by the recipient, unwrapped from the vanchor asset if different
+	pub token: CurrencyId,
+	/// Amount of the vanchor asset to release
+	pub amount: Balance,
+	/// Block from which the withdrawal can be released
+	pub release_at: BlockNumber,
+}
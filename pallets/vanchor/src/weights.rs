@@ -21,6 +21,9 @@
 //! DATE: 2022-11-18, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! HOSTNAME: ``, CPU: ``
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//!
+//! Functions marked as placeholders were added or changed by hand after this run and
+//! must be replaced by the output of the next benchmark run.
 
 // Executed Command:
 // ./target/release/webb-standalone-node
@@ -47,10 +50,17 @@ use sp_std::marker::PhantomData;
 
 pub trait WeightInfo {
 	fn create(d:u32) -> Weight;
-	fn transact() -> Weight;
-	fn register_and_transact() -> Weight;
+	fn transact(l: u32) -> Weight;
+	fn transact_with_fee_token(l: u32) -> Weight;
+	fn register_and_transact(l: u32) -> Weight;
 	fn set_max_deposit_amount() -> Weight;
 	fn set_min_withdraw_amount() -> Weight;
+	fn set_pause_status() -> Weight;
+	fn set_withdrawal_limit() -> Weight;
+	fn release_withdrawal() -> Weight;
+	fn cancel_withdrawal() -> Weight;
+	fn swap(l: u32) -> Weight;
+	fn migrate_from_mixer(l: u32) -> Weight;
 }
 
 /// Weight functions for `pallet_vanchor`.
@@ -77,10 +87,33 @@ impl<T: frame_system::Config> WeightInfo for WebbWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: MerkleTree Leaves (r:0 w:1)
 	// Storage: MerkleTree CachedRoots (r:0 w:1)
-	fn transact() -> Weight {
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn transact(l: u32, ) -> Weight {
 		Weight::from_ref_time(9_847_660_000)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: MerkleTree Trees (r:1 w:1)
+	// Storage: MerkleTree DefaultHashes (r:1 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:1 w:1)
+	// Storage: MerkleTree NextLeafIndex (r:1 w:1)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: AssetRegistry Assets (r:3 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: MerkleTree Leaves (r:0 w:1)
+	// Storage: MerkleTree CachedRoots (r:0 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn transact_with_fee_token(l: u32, ) -> Weight {
+		Weight::from_ref_time(9_921_480_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	// Storage: MerkleTree Trees (r:1 w:1)
 	// Storage: MerkleTree DefaultHashes (r:1 w:0)
@@ -91,10 +124,13 @@ impl<T: frame_system::Config> WeightInfo for WebbWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Storage: MerkleTree Leaves (r:0 w:1)
 	// Storage: MerkleTree CachedRoots (r:0 w:1)
-	fn register_and_transact() -> Weight {
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn register_and_transact(l: u32, ) -> Weight {
 		Weight::from_ref_time(10_047_340_000)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	// Storage: Vanchor MaxDepositAmount (r:1 w:1)
 	fn set_max_deposit_amount() -> Weight {
@@ -110,6 +146,82 @@ impl<T: frame_system::Config> WeightInfo for WebbWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: Vanchor ProposalNonce (r:1 w:1)
+	// Storage: Vanchor PausedVAnchors (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_pause_status() -> Weight {
+		Weight::from_ref_time(14_381_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: Vanchor WithdrawalLimits (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_withdrawal_limit() -> Weight {
+		Weight::from_ref_time(12_907_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Vanchor PendingWithdrawals (r:1 w:1)
+	// Storage: Vanchor PausedVAnchors (r:1 w:0)
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn release_withdrawal() -> Weight {
+		Weight::from_ref_time(41_372_000)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Vanchor PendingWithdrawals (r:1 w:1)
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_ref_time(39_845_000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: MerkleTree Trees (r:2 w:2)
+	// Storage: MerkleTree DefaultHashes (r:2 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:2 w:2)
+	// Storage: MerkleTree NextLeafIndex (r:2 w:2)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleTree Leaves (r:0 w:2)
+	// Storage: MerkleTree CachedRoots (r:0 w:2)
+	// Storage: Vanchor Flows (r:2 w:2)
+	// Storage: TokenWrapper WrappingFeePercent (r:1 w:0)
+	// Placeholder: estimated by hand, not benchmarked
+	fn swap(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_735_120_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	// Storage: Mixer Mixers (r:1 w:0)
+	// Storage: Mixer NullifierHashes (r:1 w:1)
+	// Storage: MerkleTree Trees (r:2 w:1)
+	// Storage: MerkleTree CachedRoots (r:1 w:1)
+	// Storage: MerkleTree DefaultHashes (r:1 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:1 w:1)
+	// Storage: MerkleTree NextLeafIndex (r:1 w:1)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: Verifier Parameters (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: MerkleTree Leaves (r:0 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn migrate_from_mixer(l: u32, ) -> Weight {
+		Weight::from_ref_time(9_881_759_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
 }
 
 impl WeightInfo for () {
@@ -134,10 +246,33 @@ impl WeightInfo for () {
 	// Storage: System Account (r:1 w:1)
 	// Storage: MerkleTree Leaves (r:0 w:1)
 	// Storage: MerkleTree CachedRoots (r:0 w:1)
-	fn transact() -> Weight {
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn transact(l: u32, ) -> Weight {
 		Weight::from_ref_time(9_847_660_000)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// Storage: MerkleTree Trees (r:1 w:1)
+	// Storage: MerkleTree DefaultHashes (r:1 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:1 w:1)
+	// Storage: MerkleTree NextLeafIndex (r:1 w:1)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: AssetRegistry Assets (r:3 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: MerkleTree Leaves (r:0 w:1)
+	// Storage: MerkleTree CachedRoots (r:0 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn transact_with_fee_token(l: u32, ) -> Weight {
+		Weight::from_ref_time(9_921_480_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	// Storage: MerkleTree Trees (r:1 w:1)
 	// Storage: MerkleTree DefaultHashes (r:1 w:0)
@@ -148,10 +283,13 @@ impl WeightInfo for () {
 	// Storage: System Account (r:1 w:1)
 	// Storage: MerkleTree Leaves (r:0 w:1)
 	// Storage: MerkleTree CachedRoots (r:0 w:1)
-	fn register_and_transact() -> Weight {
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn register_and_transact(l: u32, ) -> Weight {
 		Weight::from_ref_time(10_047_340_000)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	// Storage: Vanchor MaxDepositAmount (r:1 w:1)
 	fn set_max_deposit_amount() -> Weight {
@@ -167,4 +305,80 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: Vanchor ProposalNonce (r:1 w:1)
+	// Storage: Vanchor PausedVAnchors (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_pause_status() -> Weight {
+		Weight::from_ref_time(14_381_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: Vanchor WithdrawalLimits (r:0 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn set_withdrawal_limit() -> Weight {
+		Weight::from_ref_time(12_907_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: Vanchor PendingWithdrawals (r:1 w:1)
+	// Storage: Vanchor PausedVAnchors (r:1 w:0)
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn release_withdrawal() -> Weight {
+		Weight::from_ref_time(41_372_000)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Vanchor PendingWithdrawals (r:1 w:1)
+	// Storage: Vanchor VAnchors (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn cancel_withdrawal() -> Weight {
+		Weight::from_ref_time(39_845_000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: MerkleTree Trees (r:2 w:2)
+	// Storage: MerkleTree DefaultHashes (r:2 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:2 w:2)
+	// Storage: MerkleTree NextLeafIndex (r:2 w:2)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleTree Leaves (r:0 w:2)
+	// Storage: MerkleTree CachedRoots (r:0 w:2)
+	// Storage: Vanchor Flows (r:2 w:2)
+	// Storage: TokenWrapper WrappingFeePercent (r:1 w:0)
+	// Placeholder: estimated by hand, not benchmarked
+	fn swap(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_735_120_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	// Storage: Mixer Mixers (r:1 w:0)
+	// Storage: Mixer NullifierHashes (r:1 w:1)
+	// Storage: MerkleTree Trees (r:2 w:1)
+	// Storage: MerkleTree CachedRoots (r:1 w:1)
+	// Storage: MerkleTree DefaultHashes (r:1 w:0)
+	// Storage: BN254CircomPoseidon3x5Hasher Parameters (r:1 w:0)
+	// Storage: MerkleTree NextRootIndex (r:1 w:1)
+	// Storage: MerkleTree NextLeafIndex (r:1 w:1)
+	// Storage: Vanchor ProposalNonce (r:1 w:0)
+	// Storage: Verifier Parameters (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: MerkleTree Leaves (r:0 w:1)
+	// Storage: Vanchor Flows (r:1 w:1)
+	// Placeholder: estimated by hand, not benchmarked
+	fn migrate_from_mixer(l: u32, ) -> Weight {
+		Weight::from_ref_time(9_881_759_000)
+			.saturating_add(Weight::from_ref_time(4_000_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
 }
@@ -1,3 +1,4 @@
+use crate::types::amount::I256;
 use ark_bn254::Fr as Bn254;
 use ark_ff::{BigInteger, PrimeField};
 use sp_std::{marker::PhantomData, vec::Vec};
@@ -10,7 +11,17 @@ pub struct ArkworksIntoField<F: PrimeField>(PhantomData<F>);
 
 impl<F: PrimeField> IntoPrimeField<i128> for ArkworksIntoField<F> {
 	fn into_field(value: i128) -> Vec<u8> {
-		let mut f = F::from(value.unsigned_abs());
+		<Self as IntoPrimeField<I256>>::into_field(I256::from(value))
+	}
+}
+
+impl<F: PrimeField> IntoPrimeField<I256> for ArkworksIntoField<F> {
+	// Negative amounts wrap around the field, as `FIELD_SIZE - abs(value)` does
+	// in the EVM contracts
+	fn into_field(value: I256) -> Vec<u8> {
+		let mut abs_bytes = [0u8; 32];
+		value.unsigned_abs().to_little_endian(&mut abs_bytes);
+		let mut f = F::from_le_bytes_mod_order(&abs_bytes);
 		if value.is_negative() {
 			f = -f;
 		}
@@ -19,3 +30,29 @@ impl<F: PrimeField> IntoPrimeField<i128> for ArkworksIntoField<F> {
 }
 
 pub type ArkworksIntoFieldBn254 = ArkworksIntoField<Bn254>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::U256;
+
+	#[test]
+	fn into_field_matches_for_narrow_and_wide_amounts() {
+		for value in [0i128, 1, -1, i128::MAX, i128::MIN] {
+			let mut f = Bn254::from(value.unsigned_abs());
+			if value.is_negative() {
+				f = -f;
+			}
+			assert_eq!(ArkworksIntoFieldBn254::into_field(value), f.into_repr().to_bytes_be());
+		}
+
+		// 2^130 and -2^130 sum up to zero in the field
+		let big = I256(U256::one() << 130);
+		let minus_big = I256(U256::zero()).checked_sub(&big).unwrap();
+		let big_f = Bn254::from_be_bytes_mod_order(&ArkworksIntoFieldBn254::into_field(big));
+		let minus_big_f =
+			Bn254::from_be_bytes_mod_order(&ArkworksIntoFieldBn254::into_field(minus_big));
+		assert_eq!(big_f + minus_big_f, Bn254::from(0u64));
+		assert_eq!(big_f, Bn254::from(u128::MAX) * Bn254::from(4u64) + Bn254::from(4u64));
+	}
+}
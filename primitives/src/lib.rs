@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod hasher;
+pub mod proposals;
 pub mod runtime;
 pub mod signing;
 pub mod traits;
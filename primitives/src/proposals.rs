@@ -0,0 +1,154 @@
+//! Typed proposals in the `webb_proposals` byte format.
+//!
+//! A typed proposal is a [ProposalHeader], made of the resource ID, a function
+//! signature and the nonce, followed by a body whose layout is fixed by the
+//! function signature. The function signature is the selector of the Solidity
+//! handler function that executes the proposal on EVM chains, so signers
+//! produce the same bytes whatever the target chain is.
+use sp_core::U256;
+use sp_std::convert::{TryFrom, TryInto};
+use webb_proposals::{evm, FunctionSignature, ProposalHeader, ResourceId, TargetSystem};
+
+/// `updateEdge(bytes32,uint32,bytes32)`: an [evm::AnchorUpdateProposal]
+pub const ANCHOR_UPDATE_FUNCTION_SIG: FunctionSignature =
+	FunctionSignature([0xa0, 0x7b, 0x7e, 0x1d]);
+/// `configureMaximumDepositLimit(uint256,uint32)`: an [evm::MaxDepositLimitProposal]
+pub const MAX_DEPOSIT_LIMIT_FUNCTION_SIG: FunctionSignature =
+	FunctionSignature([0x8c, 0x83, 0x2b, 0x13]);
+/// `configureMinimalWithdrawalLimit(uint256,uint32)`: an [evm::MinWithdrawalLimitProposal]
+pub const MIN_WITHDRAWAL_LIMIT_FUNCTION_SIG: FunctionSignature =
+	FunctionSignature([0x1f, 0x7f, 0x99, 0xf7]);
+/// `setFee(uint16,uint32)`: an [evm::WrappingFeeUpdateProposal]
+pub const WRAPPING_FEE_UPDATE_FUNCTION_SIG: FunctionSignature =
+	FunctionSignature([0xc2, 0xae, 0x47, 0x20]);
+
+/// The typed proposals this chain executes, with the arguments of their call
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypedProposal {
+	/// Adds or updates the edge of `src_resource_id` on the anchor of the header's resource ID
+	AnchorUpdate { merkle_root: [u8; 32], src_resource_id: ResourceId },
+	/// Sets the maximum deposit amount of the variable anchors
+	MaxDepositLimit(u128),
+	/// Sets the minimum withdrawal amount of the variable anchors
+	MinWithdrawalLimit(u128),
+	/// Sets the wrapping fee of the pool share whose asset ID is the tree ID of the header's
+	/// resource ID
+	WrappingFeeUpdate { wrapping_fee_percent: u16, into_pool_share_id: u32 },
+}
+
+impl TypedProposal {
+	/// Decodes the proposal data of a typed proposal, header included, or returns `None` if
+	/// the function signature of the header is unknown or the data has the wrong length.
+	pub fn decode(header: &ProposalHeader, proposal_data: &[u8]) -> Option<Self> {
+		let proposal = match header.function_signature() {
+			ANCHOR_UPDATE_FUNCTION_SIG => {
+				let proposal = evm::AnchorUpdateProposal::from(
+					<[u8; evm::AnchorUpdateProposal::LENGTH]>::try_from(proposal_data).ok()?,
+				);
+				Self::AnchorUpdate {
+					merkle_root: *proposal.merkle_root(),
+					src_resource_id: proposal.src_resource_id(),
+				}
+			},
+			MAX_DEPOSIT_LIMIT_FUNCTION_SIG => {
+				let proposal = evm::MaxDepositLimitProposal::from(
+					<[u8; evm::MaxDepositLimitProposal::LENGTH]>::try_from(proposal_data).ok()?,
+				);
+				Self::MaxDepositLimit(u256_to_u128(proposal.max_deposit_limit())?)
+			},
+			MIN_WITHDRAWAL_LIMIT_FUNCTION_SIG => {
+				let proposal = evm::MinWithdrawalLimitProposal::from(
+					<[u8; evm::MinWithdrawalLimitProposal::LENGTH]>::try_from(proposal_data)
+						.ok()?,
+				);
+				Self::MinWithdrawalLimit(u256_to_u128(proposal.min_withdrawal_limit())?)
+			},
+			WRAPPING_FEE_UPDATE_FUNCTION_SIG => {
+				let proposal = evm::WrappingFeeUpdateProposal::from(
+					<[u8; evm::WrappingFeeUpdateProposal::LENGTH]>::try_from(proposal_data).ok()?,
+				);
+				// The pool share of the token wrapper is the tree of its resource ID
+				let into_pool_share_id = match header.resource_id().target_system() {
+					TargetSystem::Substrate(system) => system.tree_id,
+					_ => return None,
+				};
+				Self::WrappingFeeUpdate {
+					wrapping_fee_percent: u16::from_be_bytes(*proposal.wrapping_fee_percent()),
+					into_pool_share_id,
+				}
+			},
+			_ => return None,
+		};
+		Some(proposal)
+	}
+}
+
+/// Converts a 32 byte big-endian unsigned integer, if it fits in a `u128`
+fn u256_to_u128(value: &[u8; 32]) -> Option<u128> {
+	U256::from_big_endian(value).try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::derive_resource_id;
+	use webb_proposals::{Nonce, SubstrateTargetSystem};
+
+	fn proposal_data(
+		r_id: ResourceId,
+		function_signature: FunctionSignature,
+		body: &[u8],
+	) -> (ProposalHeader, Vec<u8>) {
+		let header = ProposalHeader::new(r_id, function_signature, Nonce::new(1));
+		let mut proposal_data = r_id.0.to_vec();
+		proposal_data.extend_from_slice(&function_signature.0);
+		proposal_data.extend_from_slice(&1u32.to_be_bytes());
+		proposal_data.extend_from_slice(body);
+		(header, proposal_data)
+	}
+
+	#[test]
+	fn wrapping_fee_update_targets_the_pool_share_of_the_resource_tree() {
+		let r_id = derive_resource_id(1080, SubstrateTargetSystem { pallet_index: 9, tree_id: 7 });
+		let (header, data) = proposal_data(r_id, WRAPPING_FEE_UPDATE_FUNCTION_SIG, &[0, 5]);
+		assert_eq!(
+			TypedProposal::decode(&header, &data),
+			Some(TypedProposal::WrappingFeeUpdate {
+				wrapping_fee_percent: 5,
+				into_pool_share_id: 7
+			})
+		);
+		// the fee is a single uint16
+		let (header, data) = proposal_data(r_id, WRAPPING_FEE_UPDATE_FUNCTION_SIG, &[0, 0, 5]);
+		assert_eq!(TypedProposal::decode(&header, &data), None);
+	}
+
+	#[test]
+	fn anchor_update_is_decoded_from_the_evm_layout() {
+		let r_id = derive_resource_id(1080, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+		let src_r_id =
+			derive_resource_id(1081, SubstrateTargetSystem { pallet_index: 2, tree_id: 3 });
+		let mut body = [1u8; 32].to_vec();
+		body.extend_from_slice(&src_r_id.0);
+		let (header, data) = proposal_data(r_id, ANCHOR_UPDATE_FUNCTION_SIG, &body);
+		assert_eq!(
+			TypedProposal::decode(&header, &data),
+			Some(TypedProposal::AnchorUpdate { merkle_root: [1u8; 32], src_resource_id: src_r_id })
+		);
+	}
+
+	#[test]
+	fn limits_must_fit_in_a_u128() {
+		let r_id = derive_resource_id(1080, SubstrateTargetSystem { pallet_index: 2, tree_id: 1 });
+		let mut limit = [0u8; 32];
+		limit[31] = 100;
+		let (header, data) = proposal_data(r_id, MAX_DEPOSIT_LIMIT_FUNCTION_SIG, &limit);
+		assert_eq!(
+			TypedProposal::decode(&header, &data),
+			Some(TypedProposal::MaxDepositLimit(100))
+		);
+		limit[0] = 1;
+		let (header, data) = proposal_data(r_id, MIN_WITHDRAWAL_LIMIT_FUNCTION_SIG, &limit);
+		assert_eq!(TypedProposal::decode(&header, &data), None);
+	}
+}
@@ -1,3 +1,7 @@
+use super::{
+	PROPOSAL_BATCH_TAG, SET_MAINTAINERS_TAG, SET_MAINTAINER_TAG, SET_TYPED_MAINTAINER_TAG,
+	UPDATE_BRIDGE_STATUS_TAG,
+};
 pub use sp_io::{hashing::keccak_256, EcdsaVerifyError};
 use sp_std::vec::Vec;
 
@@ -16,14 +20,201 @@ pub fn validate_ecdsa_signature(data: &[u8], signature: &[u8]) -> bool {
 	}
 }
 
+/// Prefix of EIP-191 `personal_sign` messages holding a 32 byte hash
+pub const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+/// Type of the EIP-712 domain of bridge messages.
+///
+/// The chain is identified by its typed chain ID instead of an EVM `chainId`, which
+/// wallets would check against the network they are connected to.
+pub const EIP712_DOMAIN_TYPE: &[u8] =
+	b"EIP712Domain(string name,string version,uint64 chainIdType,bytes32 salt)";
+/// Type of the EIP-712 struct of typed proposals, split along their header
+pub const EIP712_PROPOSAL_TYPE: &[u8] =
+	b"Proposal(bytes32 resourceId,bytes4 functionSignature,uint32 nonce,bytes body)";
+/// Type of the EIP-712 struct of the root of a proposal batch
+pub const EIP712_PROPOSAL_BATCH_TYPE: &[u8] = b"ProposalBatch(bytes32 root)";
+/// Type of the EIP-712 struct of `set_maintainer` messages
+pub const EIP712_SET_MAINTAINER_TYPE: &[u8] = b"SetMaintainer(uint32 nonce,bytes maintainer)";
+/// Type of the EIP-712 struct of `set_maintainers` messages
+pub const EIP712_SET_MAINTAINERS_TYPE: &[u8] = b"SetMaintainers(uint32 nonce,bytes maintainers)";
+/// Type of the EIP-712 struct of `set_typed_maintainer` messages
+pub const EIP712_SET_TYPED_MAINTAINER_TYPE: &[u8] =
+	b"SetTypedMaintainer(uint32 nonce,bytes maintainer)";
+/// Type of the EIP-712 struct of `update_bridge_status` messages
+pub const EIP712_UPDATE_BRIDGE_STATUS_TYPE: &[u8] =
+	b"UpdateBridgeStatus(uint32 nonce,bytes status)";
+
+/// Length of the header of a typed proposal
+const PROPOSAL_HEADER_LENGTH: usize = 32 + 4 + 4;
+
 pub fn recover_ecdsa_pub_key(data: &[u8], signature: &[u8]) -> Result<Vec<u8>, EcdsaVerifyError> {
+	recover_ecdsa_pub_key_prehashed(&keccak_256(data), signature)
+}
+
+/// Recovers the uncompressed public key that signed the 32 byte `hash`
+pub fn recover_ecdsa_pub_key_prehashed(
+	hash: &[u8; 32],
+	signature: &[u8],
+) -> Result<Vec<u8>, EcdsaVerifyError> {
 	if signature.len() == SIGNATURE_LENGTH {
 		let mut sig = [0u8; SIGNATURE_LENGTH];
 		sig[..SIGNATURE_LENGTH].copy_from_slice(signature);
 
-		let hash = keccak_256(data);
-		let pub_key = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &hash)?;
+		let pub_key = sp_io::crypto::secp256k1_ecdsa_recover(&sig, hash)?;
 		return Ok(pub_key.to_vec())
 	}
 	Err(EcdsaVerifyError::BadSignature)
 }
+
+/// Returns the hash an Ethereum wallet signs for `personal_sign(keccak256(data))`
+pub fn eip191_hash(data: &[u8]) -> [u8; 32] {
+	let mut message = EIP191_PREFIX.to_vec();
+	message.extend_from_slice(&keccak_256(data));
+	keccak_256(&message)
+}
+
+/// Returns the separator of the EIP-712 domain named `name` at `version`, for
+/// the chain of `chain_id_type` and the bridge instance identified by `salt`
+pub fn eip712_domain_separator(
+	name: &[u8],
+	version: &[u8],
+	chain_id_type: u64,
+	salt: [u8; 32],
+) -> [u8; 32] {
+	let mut domain = keccak_256(EIP712_DOMAIN_TYPE).to_vec();
+	domain.extend_from_slice(&keccak_256(name));
+	domain.extend_from_slice(&keccak_256(version));
+	domain.extend_from_slice(&eip712_uint(chain_id_type));
+	domain.extend_from_slice(&salt);
+	keccak_256(&domain)
+}
+
+/// Returns the hash signed for the bridge message `message` in the EIP-712 domain of
+/// `domain_separator`, or `None` if the message does not fit any of its struct types.
+///
+/// Messages starting with the tag of a maintainer call or of a proposal batch are signed as
+/// the struct of that kind, with the nonce split from the body. Any other message is signed
+/// as a typed `Proposal`.
+pub fn eip712_hash(domain_separator: &[u8; 32], message: &[u8]) -> Option<[u8; 32]> {
+	let encoded = if let Some(root) = message.strip_prefix(PROPOSAL_BATCH_TAG) {
+		if root.len() != 32 {
+			return None
+		}
+		let mut encoded = keccak_256(EIP712_PROPOSAL_BATCH_TYPE).to_vec();
+		encoded.extend_from_slice(root);
+		encoded
+	} else if let Some(rest) = message.strip_prefix(SET_MAINTAINER_TAG) {
+		eip712_maintainer_message(EIP712_SET_MAINTAINER_TYPE, rest)?
+	} else if let Some(rest) = message.strip_prefix(SET_MAINTAINERS_TAG) {
+		eip712_maintainer_message(EIP712_SET_MAINTAINERS_TYPE, rest)?
+	} else if let Some(rest) = message.strip_prefix(SET_TYPED_MAINTAINER_TAG) {
+		eip712_maintainer_message(EIP712_SET_TYPED_MAINTAINER_TYPE, rest)?
+	} else if let Some(rest) = message.strip_prefix(UPDATE_BRIDGE_STATUS_TAG) {
+		eip712_maintainer_message(EIP712_UPDATE_BRIDGE_STATUS_TYPE, rest)?
+	} else {
+		if message.len() < PROPOSAL_HEADER_LENGTH {
+			return None
+		}
+		let (header, body) = message.split_at(PROPOSAL_HEADER_LENGTH);
+		let mut function_signature = [0u8; 32];
+		function_signature[..4].copy_from_slice(&header[32..36]);
+		let mut encoded = keccak_256(EIP712_PROPOSAL_TYPE).to_vec();
+		encoded.extend_from_slice(&header[..32]);
+		encoded.extend_from_slice(&function_signature);
+		encoded.extend_from_slice(&eip712_nonce(&header[36..]));
+		encoded.extend_from_slice(&keccak_256(body));
+		encoded
+	};
+	let mut digest = b"\x19\x01".to_vec();
+	digest.extend_from_slice(domain_separator);
+	digest.extend_from_slice(&keccak_256(&encoded));
+	Some(keccak_256(&digest))
+}
+
+/// Encodes a maintainer message made of a 4 byte nonce and a body as the struct `type_`
+fn eip712_maintainer_message(type_: &[u8], message: &[u8]) -> Option<Vec<u8>> {
+	if message.len() < 4 {
+		return None
+	}
+	let (nonce, body) = message.split_at(4);
+	let mut encoded = keccak_256(type_).to_vec();
+	encoded.extend_from_slice(&eip712_nonce(nonce));
+	encoded.extend_from_slice(&keccak_256(body));
+	Some(encoded)
+}
+
+/// Encodes a 4 byte big-endian nonce as a `uint32`
+fn eip712_nonce(nonce: &[u8]) -> [u8; 32] {
+	let mut encoded = [0u8; 32];
+	encoded[28..].copy_from_slice(nonce);
+	encoded
+}
+
+/// Encodes an unsigned integer as a 32 byte big-endian word
+fn eip712_uint(value: u64) -> [u8; 32] {
+	let mut encoded = [0u8; 32];
+	encoded[24..].copy_from_slice(&value.to_be_bytes());
+	encoded
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DOMAIN: [u8; 32] = [7u8; 32];
+
+	fn typed_data_hash(struct_hash: [u8; 32]) -> [u8; 32] {
+		keccak_256(&[&b"\x19\x01"[..], &DOMAIN, &struct_hash].concat())
+	}
+
+	#[test]
+	fn eip712_hash_uses_the_struct_of_the_message_kind() {
+		let nonce = 3u32.to_be_bytes();
+		let body = b"maintainers";
+		let message = [SET_MAINTAINERS_TAG, &nonce, body].concat();
+		let struct_hash = keccak_256(
+			&[&keccak_256(EIP712_SET_MAINTAINERS_TYPE)[..], &eip712_uint(3), &keccak_256(body)]
+				.concat(),
+		);
+		assert_eq!(eip712_hash(&DOMAIN, &message), Some(typed_data_hash(struct_hash)));
+
+		// the same nonce and body under another tag is another struct
+		let message = [UPDATE_BRIDGE_STATUS_TAG, &nonce, body].concat();
+		assert_ne!(eip712_hash(&DOMAIN, &message), Some(typed_data_hash(struct_hash)));
+
+		// set_maintainer messages have their own struct
+		let key = [4u8; 64];
+		let message = [SET_MAINTAINER_TAG, &nonce, &key].concat();
+		let struct_hash = keccak_256(
+			&[&keccak_256(EIP712_SET_MAINTAINER_TYPE)[..], &eip712_uint(3), &keccak_256(&key)]
+				.concat(),
+		);
+		assert_eq!(eip712_hash(&DOMAIN, &message), Some(typed_data_hash(struct_hash)));
+
+		// untagged messages are typed proposals
+		let proposal = [&[1u8; 32][..], &[0xa0, 0x7b, 0x7e, 0x1d], &nonce, body].concat();
+		let mut function_signature = [0u8; 32];
+		function_signature[..4].copy_from_slice(&[0xa0, 0x7b, 0x7e, 0x1d]);
+		let struct_hash = keccak_256(
+			&[
+				&keccak_256(EIP712_PROPOSAL_TYPE)[..],
+				&[1u8; 32],
+				&function_signature,
+				&eip712_uint(3),
+				&keccak_256(body),
+			]
+			.concat(),
+		);
+		assert_eq!(eip712_hash(&DOMAIN, &proposal), Some(typed_data_hash(struct_hash)));
+	}
+
+	#[test]
+	fn eip712_hash_rejects_messages_of_no_kind() {
+		// shorter than a proposal header
+		assert_eq!(eip712_hash(&DOMAIN, &[0u8; 39]), None);
+		// a batch root is 32 bytes
+		assert_eq!(eip712_hash(&DOMAIN, &[PROPOSAL_BATCH_TAG, &[0u8; 31]].concat()), None);
+		// a maintainer message starts with a nonce
+		assert_eq!(eip712_hash(&DOMAIN, &[SET_TYPED_MAINTAINER_TAG, &[0u8; 3]].concat()), None);
+	}
+}
@@ -1,5 +1,30 @@
-use sp_std::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{marker::PhantomData, vec::Vec};
 pub mod ecdsa;
+pub mod ed25519;
+pub mod schnorr;
+pub mod sr25519;
+
+/// Prefix of the messages of `set_maintainer`.
+///
+/// Maintainer messages start with the tag of their call, so that a message signed for one
+/// call cannot be submitted to another. No tag is a prefix of another.
+pub const SET_MAINTAINER_TAG: &[u8] = b"WEBB_SET_ECDSA_MAINTAINER";
+
+/// Prefix of the messages of `set_maintainers`.
+pub const SET_MAINTAINERS_TAG: &[u8] = b"WEBB_SET_MAINTAINERS";
+
+/// Prefix of the messages of `set_typed_maintainer`.
+pub const SET_TYPED_MAINTAINER_TAG: &[u8] = b"WEBB_SET_TYPED_MAINTAINER";
+
+/// Prefix of the messages of `update_bridge_status`.
+pub const UPDATE_BRIDGE_STATUS_TAG: &[u8] = b"WEBB_UPDATE_BRIDGE_STATUS";
+
+/// Prefix of the message signed for the root of a proposal batch.
+pub const PROPOSAL_BATCH_TAG: &[u8] = b"WEBB_PROPOSAL_BATCH";
 
 pub trait SigningSystem {
 	type Error;
@@ -12,6 +37,38 @@ pub trait SigningSystem {
 	fn recover_pub_key(msg: &[u8], sig: &[u8]) -> Result<Vec<u8>, Self::Error>;
 }
 
+/// The signature scheme a maintainer key belongs to
+#[derive(
+	Encode, Decode, Default, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum KeyType {
+	/// secp256k1 ECDSA over keccak256, as used by Ethereum and the DKG
+	#[default]
+	Ecdsa,
+	/// Schnorrkel signatures over Ristretto, as used by Substrate accounts
+	Sr25519,
+	/// Ed25519 signatures
+	Ed25519,
+	/// BIP-340 Schnorr over keccak256, as produced by a FROST signer set
+	Schnorr,
+}
+
+impl KeyType {
+	/// Returns the length in bytes of a public key of this type
+	pub fn public_key_length(&self) -> usize {
+		match self {
+			KeyType::Ecdsa => 64,
+			KeyType::Sr25519 => sr25519::PUBLIC_KEY_LENGTH,
+			KeyType::Ed25519 => ed25519::PUBLIC_KEY_LENGTH,
+			KeyType::Schnorr => schnorr::PUBLIC_KEY_LENGTH,
+		}
+	}
+}
+
+/// Error for signing systems that cannot recover a public key from a signature
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct KeyRecoveryUnsupported;
+
 pub struct SignatureVerifier;
 
 impl SigningSystem for SignatureVerifier {
@@ -22,3 +79,77 @@ impl SigningSystem for SignatureVerifier {
 		ecdsa::recover_ecdsa_pub_key(msg, sig)
 	}
 }
+
+/// Verifies ECDSA signatures over the EIP-191 `personal_sign` hash of a
+/// message, as shown by Ethereum wallets.
+pub struct Eip191SignatureVerifier;
+
+impl SigningSystem for Eip191SignatureVerifier {
+	type Error = ecdsa::EcdsaVerifyError;
+
+	/// returns the uncompressed public key(64bytes) without the 0x04 prefix
+	fn recover_pub_key(msg: &[u8], sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		ecdsa::recover_ecdsa_pub_key_prehashed(&ecdsa::eip191_hash(msg), sig)
+	}
+}
+
+/// Verifies ECDSA signatures over a message as EIP-712 typed data, in the domain
+/// whose separator is returned by `Domain`.
+///
+/// The struct type depends on the kind of the message, see [ecdsa::eip712_hash]. The
+/// domain is built with [ecdsa::eip712_domain_separator] from the chain ID type and
+/// the bridge instance, so that a signature is only valid for one bridge.
+pub struct Eip712SignatureVerifier<Domain>(PhantomData<Domain>);
+
+impl<Domain: Get<[u8; 32]>> SigningSystem for Eip712SignatureVerifier<Domain> {
+	type Error = ecdsa::EcdsaVerifyError;
+
+	/// returns the uncompressed public key(64bytes) without the 0x04 prefix
+	fn recover_pub_key(msg: &[u8], sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		let hash =
+			ecdsa::eip712_hash(&Domain::get(), msg).ok_or(ecdsa::EcdsaVerifyError::BadSignature)?;
+		ecdsa::recover_ecdsa_pub_key_prehashed(&hash, sig)
+	}
+}
+
+pub struct Sr25519Verifier;
+
+impl SigningSystem for Sr25519Verifier {
+	type Error = KeyRecoveryUnsupported;
+
+	fn verify(key: &[u8], msg: &[u8], sig: &[u8]) -> Result<bool, Self::Error> {
+		Ok(sr25519::verify_sr25519_signature(key, msg, sig))
+	}
+
+	fn recover_pub_key(_msg: &[u8], _sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		Err(KeyRecoveryUnsupported)
+	}
+}
+
+pub struct Ed25519Verifier;
+
+impl SigningSystem for Ed25519Verifier {
+	type Error = KeyRecoveryUnsupported;
+
+	fn verify(key: &[u8], msg: &[u8], sig: &[u8]) -> Result<bool, Self::Error> {
+		Ok(ed25519::verify_ed25519_signature(key, msg, sig))
+	}
+
+	fn recover_pub_key(_msg: &[u8], _sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		Err(KeyRecoveryUnsupported)
+	}
+}
+
+pub struct SchnorrVerifier;
+
+impl SigningSystem for SchnorrVerifier {
+	type Error = KeyRecoveryUnsupported;
+
+	fn verify(key: &[u8], msg: &[u8], sig: &[u8]) -> Result<bool, Self::Error> {
+		Ok(schnorr::verify_schnorr_signature(key, msg, sig))
+	}
+
+	fn recover_pub_key(_msg: &[u8], _sig: &[u8]) -> Result<Vec<u8>, Self::Error> {
+		Err(KeyRecoveryUnsupported)
+	}
+}
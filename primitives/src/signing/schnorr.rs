@@ -0,0 +1,20 @@
+use k256::schnorr::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+pub use sp_io::hashing::keccak_256;
+use sp_std::convert::TryFrom;
+
+pub const SIGNATURE_LENGTH: usize = 64;
+pub const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Verifies a BIP-340 Schnorr signature over `keccak256(data)` made with the x-only
+/// public key `key`, as produced by a FROST signer set over secp256k1.
+pub fn verify_schnorr_signature(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+	if key.len() != PUBLIC_KEY_LENGTH || signature.len() != SIGNATURE_LENGTH {
+		return false
+	}
+	let (key, signature) = match (VerifyingKey::from_bytes(key), Signature::try_from(signature)) {
+		(Ok(key), Ok(signature)) => (key, signature),
+		_ => return false,
+	};
+
+	key.verify_prehash(&keccak_256(data), &signature).is_ok()
+}
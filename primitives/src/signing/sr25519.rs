@@ -0,0 +1,16 @@
+use sp_core::sr25519::{Public, Signature};
+use sp_std::convert::TryInto;
+
+pub const SIGNATURE_LENGTH: usize = 64;
+pub const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Verifies an sr25519 signature over the raw `data` made with the public key `key`.
+pub fn verify_sr25519_signature(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+	let (key, signature): ([u8; PUBLIC_KEY_LENGTH], [u8; SIGNATURE_LENGTH]) =
+		match (key.try_into(), signature.try_into()) {
+			(Ok(key), Ok(signature)) => (key, signature),
+			_ => return false,
+		};
+
+	sp_io::crypto::sr25519_verify(&Signature::from_raw(signature), data, &Public::from_raw(key))
+}
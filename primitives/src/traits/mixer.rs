@@ -16,9 +16,10 @@ pub trait MixerInterface<AccountId, Balance, CurrencyId, TreeId, Element> {
 		id: TreeId,
 		leaf: Element,
 	) -> Result<(), dispatch::DispatchError>;
-	/// Withdraw from the mixer
+	/// Withdraw from the mixer, on behalf of `transactor`
 	#[allow(clippy::too_many_arguments)]
 	fn withdraw(
+		transactor: AccountId,
 		id: TreeId,
 		proof_bytes: &[u8],
 		root: Element,
@@ -33,6 +34,48 @@ pub trait MixerInterface<AccountId, Balance, CurrencyId, TreeId, Element> {
 		id: TreeId,
 		nullifier_hash: Element,
 	) -> Result<(), dispatch::DispatchError>;
+	/// Gets the deposit size and asset of a mixer
+	fn deposit_info(id: TreeId) -> Result<(Balance, CurrencyId), dispatch::DispatchError>;
+}
+
+/// Used by runtimes without a mixer, every call fails
+impl<AccountId, Balance, CurrencyId, TreeId, Element>
+	MixerInterface<AccountId, Balance, CurrencyId, TreeId, Element> for ()
+{
+	fn create(
+		_: Option<AccountId>,
+		_: Balance,
+		_: u8,
+		_: CurrencyId,
+	) -> Result<TreeId, dispatch::DispatchError> {
+		Err(dispatch::DispatchError::Other("No mixer"))
+	}
+
+	fn deposit(_: AccountId, _: TreeId, _: Element) -> Result<(), dispatch::DispatchError> {
+		Err(dispatch::DispatchError::Other("No mixer"))
+	}
+
+	fn withdraw(
+		_: AccountId,
+		_: TreeId,
+		_: &[u8],
+		_: Element,
+		_: Element,
+		_: AccountId,
+		_: AccountId,
+		_: Balance,
+		_: Balance,
+	) -> Result<(), dispatch::DispatchError> {
+		Err(dispatch::DispatchError::Other("No mixer"))
+	}
+
+	fn add_nullifier_hash(_: TreeId, _: Element) -> Result<(), dispatch::DispatchError> {
+		Err(dispatch::DispatchError::Other("No mixer"))
+	}
+
+	fn deposit_info(_: TreeId) -> Result<(Balance, CurrencyId), dispatch::DispatchError> {
+		Err(dispatch::DispatchError::Other("No mixer"))
+	}
 }
 
 /// Mixer trait for inspecting mixer state
@@ -4,4 +4,5 @@ pub mod linkable_tree;
 pub mod merkle_tree;
 pub mod mixer;
 pub mod signature_bridge;
+pub mod transact_filter;
 pub mod vanchor;
@@ -1,5 +1,6 @@
 //! All the traits exposed to be used in other custom pallets
 use frame_support::{dispatch, BoundedVec};
+use webb_proposals::ProposalHeader;
 
 /// Post-processing hook for setting a maintainer after a maintainer is selected
 /// in some external process.
@@ -10,3 +11,25 @@ pub trait SetMaintainer<N, M> {
 		maintainer: BoundedVec<u8, M>,
 	) -> Result<(), dispatch::DispatchError>;
 }
+
+/// Checks signatures made by the maintainer(s) of a bridge, for pallets that
+/// have messages signed without executing them through the bridge.
+pub trait VerifyMaintainerSignature {
+	/// Returns whether `signature` over `message` is valid for the active maintainer(s).
+	fn verify_maintainer_signature(message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Maps proposals in the typed `webb_proposals` byte format to the call that
+/// executes them on this chain.
+pub trait TypedProposalDecoder<Call> {
+	/// Returns the call of the proposal with this header, whose data includes the
+	/// header, or `None` if the function signature is not a typed proposal known
+	/// to this chain.
+	fn decode_proposal(header: &ProposalHeader, proposal_data: &[u8]) -> Option<Call>;
+}
+
+impl<Call> TypedProposalDecoder<Call> for () {
+	fn decode_proposal(_header: &ProposalHeader, _proposal_data: &[u8]) -> Option<Call> {
+		None
+	}
+}
@@ -0,0 +1,29 @@
+//! Screening of the accounts taking part in a transaction
+use frame_support::dispatch;
+
+/// Checks the parties of a deposit or withdrawal before any state is changed.
+///
+/// Deposits have no recipient or relayer of their own, so the depositor is
+/// passed in their place.
+pub trait TransactFilter<AccountId, CurrencyId, Balance> {
+	/// Returns an error if the transaction must be rejected
+	fn filter(
+		transactor: &AccountId,
+		recipient: &AccountId,
+		relayer: &AccountId,
+		asset: CurrencyId,
+		amount: Balance,
+	) -> dispatch::DispatchResult;
+}
+
+impl<AccountId, CurrencyId, Balance> TransactFilter<AccountId, CurrencyId, Balance> for () {
+	fn filter(
+		_: &AccountId,
+		_: &AccountId,
+		_: &AccountId,
+		_: CurrencyId,
+		_: Balance,
+	) -> dispatch::DispatchResult {
+		Ok(())
+	}
+}
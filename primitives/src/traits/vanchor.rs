@@ -1,5 +1,5 @@
 //! All the traits exposed to be used in other custom pallets
-use crate::types::vanchor::{ExtData, ProofData};
+use crate::types::vanchor::{ExtData, PauseStatus, ProofData};
 use codec::Encode;
 use frame_support::dispatch;
 use sp_std::vec::Vec;
@@ -74,6 +74,13 @@ pub trait VAnchorInterface<C: VAnchorConfig> {
 		min_withdraw_amount: C::Balance,
 		nonce: C::ProposalNonce,
 	) -> Result<(), dispatch::DispatchError>;
+
+	/// Pause the given operations of this tree, or resume them with `None`
+	fn set_pause_status(
+		id: C::TreeId,
+		status: Option<PauseStatus>,
+		nonce: C::ProposalNonce,
+	) -> Result<(), dispatch::DispatchError>;
 }
 
 /// Anchor trait for inspecting tree state
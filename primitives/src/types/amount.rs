@@ -0,0 +1,157 @@
+use super::{IntoAbiToken, Token};
+use codec::{Decode, Encode, MaxEncodedLen};
+use ethabi::Int;
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_core::U256;
+
+/// A signed 256-bit amount in two's complement, matching the EVM `int256`.
+///
+/// Its SCALE encoding is the 32 byte little-endian two's complement, so amounts
+/// of any width can be widened into it from their own encoding.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct I256(pub U256);
+
+impl I256 {
+	/// Sign-extends a little-endian two's complement integer, returning `None` if it
+	/// is longer than 32 bytes.
+	pub fn from_signed_le_bytes(bytes: &[u8]) -> Option<Self> {
+		if bytes.len() > 32 {
+			return None
+		}
+		let negative = bytes.last().map_or(false, |byte| byte & 0x80 != 0);
+		let mut bytes32 = if negative { [0xff; 32] } else { [0; 32] };
+		bytes32[..bytes.len()].copy_from_slice(bytes);
+		Some(Self(U256::from_little_endian(&bytes32)))
+	}
+
+	/// Zero-extends a little-endian unsigned integer, returning `None` if it is
+	/// longer than 32 bytes.
+	pub fn from_unsigned_le_bytes(bytes: &[u8]) -> Option<Self> {
+		if bytes.len() > 32 {
+			return None
+		}
+		Some(Self(U256::from_little_endian(bytes)))
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+
+	pub fn is_negative(&self) -> bool {
+		self.0.bit(255)
+	}
+
+	pub fn is_positive(&self) -> bool {
+		!self.is_negative() && !self.is_zero()
+	}
+
+	/// Absolute value of the amount, which fits in a `U256` even for the minimum.
+	pub fn unsigned_abs(&self) -> U256 {
+		if self.is_negative() {
+			(!self.0).overflowing_add(U256::one()).0
+		} else {
+			self.0
+		}
+	}
+
+	/// Absolute value of the amount, if it fits in a `u128`.
+	pub fn unsigned_abs_u128(&self) -> Option<u128> {
+		let abs = self.unsigned_abs();
+		if abs.bits() > 128 {
+			return None
+		}
+		Some(abs.low_u128())
+	}
+
+	/// Subtracts `other`, returning `None` if the result does not fit in 256 bits.
+	pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+		let result = Self(self.0.overflowing_sub(other.0).0);
+		// Overflow only happens when the operands have different signs and the
+		// sign of the result differs from the sign of `self`
+		if self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative() {
+			return None
+		}
+		Some(result)
+	}
+
+	pub fn to_le_bytes(&self) -> [u8; 32] {
+		let mut bytes = [0u8; 32];
+		self.0.to_little_endian(&mut bytes);
+		bytes
+	}
+}
+
+impl From<i128> for I256 {
+	fn from(value: i128) -> Self {
+		let abs = Self(U256::from(value.unsigned_abs()));
+		if value.is_negative() {
+			Self(U256::zero()).checked_sub(&abs).unwrap_or_default()
+		} else {
+			abs
+		}
+	}
+}
+
+impl From<u128> for I256 {
+	fn from(value: u128) -> Self {
+		Self(U256::from(value))
+	}
+}
+
+impl IntoAbiToken for I256 {
+	fn into_abi(&self) -> Token {
+		Token::Int(Int::from_little_endian(&self.to_le_bytes()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn i256_sign_extends_narrower_amounts() {
+		let minus_one = I256::from(-1i128);
+		assert!(minus_one.is_negative());
+		assert_eq!(minus_one.0, U256::MAX);
+		assert_eq!(minus_one.unsigned_abs(), U256::one());
+		assert_eq!(I256::from(i128::MIN).unsigned_abs(), U256::from(i128::MIN.unsigned_abs()));
+		assert_eq!(I256::from_signed_le_bytes(&(-5i32).to_le_bytes()), Some(I256::from(-5i128)));
+		assert_eq!(
+			I256::from_unsigned_le_bytes(&u128::MAX.to_le_bytes()),
+			Some(I256::from(u128::MAX))
+		);
+		assert!(!I256::from(u128::MAX).is_negative());
+		assert!(I256::from(u128::MAX).is_positive());
+		assert!(!I256::default().is_positive());
+	}
+
+	#[test]
+	fn i256_rejects_inputs_longer_than_32_bytes() {
+		assert_eq!(I256::from_signed_le_bytes(&[0xff; 33]), None);
+		assert_eq!(I256::from_unsigned_le_bytes(&[0; 33]), None);
+		assert_eq!(I256::from_signed_le_bytes(&[0xff; 32]), Some(I256::from(-1i128)));
+	}
+
+	#[test]
+	fn i256_unsigned_abs_u128_checks_the_range() {
+		assert_eq!(I256::from(-7i128).unsigned_abs_u128(), Some(7));
+		assert_eq!(I256::from(u128::MAX).unsigned_abs_u128(), Some(u128::MAX));
+		assert_eq!(I256(U256::one() << 128).unsigned_abs_u128(), None);
+	}
+
+	#[test]
+	fn i256_checked_sub_detects_overflow() {
+		let above_i128 = I256::from(u128::MAX);
+		assert_eq!(above_i128.checked_sub(&I256::from(1u128)), Some(I256::from(u128::MAX - 1)));
+		assert_eq!(I256::from(1i128).checked_sub(&I256::from(3i128)), Some(I256::from(-2i128)));
+
+		let min = I256(U256::one() << 255);
+		assert_eq!(min.unsigned_abs(), U256::one() << 255);
+		assert_eq!(min.checked_sub(&I256::from(1i128)), None);
+		let max = I256((U256::one() << 255) - 1);
+		assert_eq!(max.checked_sub(&I256::from(-1i128)), None);
+	}
+}
@@ -0,0 +1,12 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixerMetadata<Balance, AssetId> {
+	/// Balance size of deposit
+	pub deposit_size: Balance,
+	/// Option of specifying a fungible asset. When None, the asset is the
+	/// native currency.
+	pub asset: AssetId,
+}
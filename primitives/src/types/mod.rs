@@ -1,4 +1,6 @@
+pub mod amount;
 pub mod anonymity_mining;
+pub mod mixer;
 pub mod runtime;
 pub mod vanchor;
 
@@ -98,14 +100,10 @@ pub trait IntoAbiToken {
 	}
 }
 
+// Sign-extended to 256 bits, like an `int256` in the EVM
 impl IntoAbiToken for i128 {
 	fn into_abi(&self) -> Token {
-		let bytes = self.encode();
-		let mut bytes32: [u8; 32] = [0; 32];
-		for (i, byte) in bytes.iter().enumerate() {
-			bytes32[i] = *byte;
-		}
-		Token::Int(bytes32.into())
+		amount::I256::from(*self).into_abi()
 	}
 }
 
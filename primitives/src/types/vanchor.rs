@@ -1,7 +1,9 @@
 use super::{ElementTrait, IntoAbiToken, Token};
 use codec::{Decode, Encode, MaxEncodedLen};
-use ethabi::{Int, Uint};
+use core::convert::TryInto;
+use ethabi::Uint;
 use scale_info::TypeInfo;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{vec, vec::Vec};
 
 #[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -13,6 +15,64 @@ pub struct VAnchorMetadata<AccountId, CurrencyId> {
 	pub asset: CurrencyId,
 }
 
+/// The operations halted on a paused vanchor.
+#[derive(Clone, Copy, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum PauseStatus {
+	/// Only deposits are rejected, so funds can still be withdrawn.
+	Deposits,
+	/// Every transaction is rejected.
+	All,
+}
+
+/// Cumulative flows of funds through a vanchor, in units of its asset
+#[derive(Clone, Encode, Decode, Debug, Default, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct TreeFlows<Balance> {
+	/// Total amount deposited into the tree
+	pub deposited: Balance,
+	/// Total amount withdrawn from the tree, excluding fees
+	pub withdrawn: Balance,
+	/// Total fees paid to relayers and block authors
+	pub fees_paid: Balance,
+	/// Amount currently locked in the tree
+	pub locked: Balance,
+	/// Amount of withdrawals from the tree held back by its withdrawal limit and
+	/// still owed by the pallet
+	pub held_back: Balance,
+}
+
+/// Holdings of the vanchor pallet account in one asset compared to the amount
+/// owed by the vanchors of that asset
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct AssetSolvency<CurrencyId, Balance> {
+	pub asset: CurrencyId,
+	/// Sum of the locked and held back balances of every vanchor of the asset
+	pub locked: Balance,
+	/// Balance of the pallet account in the asset
+	pub held: Balance,
+	/// Amount held beyond what is locked. The account can hold more than is
+	/// locked, since anyone can transfer funds to it.
+	pub surplus: Balance,
+	/// Amount locked beyond what is held
+	pub shortfall: Balance,
+}
+
+impl<CurrencyId, Balance: Saturating + Zero + Copy> AssetSolvency<CurrencyId, Balance> {
+	pub fn new(asset: CurrencyId, locked: Balance, held: Balance) -> Self {
+		Self {
+			asset,
+			locked,
+			held,
+			surplus: held.saturating_sub(locked),
+			shortfall: locked.saturating_sub(held),
+		}
+	}
+
+	/// Whether the pallet account holds enough to cover every vanchor of the asset.
+	pub fn is_solvent(&self) -> bool {
+		self.shortfall.is_zero()
+	}
+}
+
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
 pub struct ProofData<E> {
 	pub proof: Vec<u8>,
@@ -46,6 +106,8 @@ pub struct ExtData<AccountId: Encode, Amount: Encode, Balance: Encode, CurrencyI
 	pub token: CurrencyId,
 	pub encrypted_output1: Vec<u8>,
 	pub encrypted_output2: Vec<u8>,
+	/// Token the relayer fee is unwrapped into, if different from the anchor asset
+	pub fee_token: Option<CurrencyId>,
 }
 
 impl<I: Encode, A: Encode, B: Encode, C: Encode> ExtData<I, A, B, C> {
@@ -69,16 +131,34 @@ impl<I: Encode, A: Encode, B: Encode, C: Encode> ExtData<I, A, B, C> {
 			token,
 			encrypted_output1,
 			encrypted_output2,
+			fee_token: None,
 		}
 	}
+
+	/// Pays the relayer fee in `fee_token`, unwrapped from the anchor asset.
+	pub fn with_fee_token(mut self, fee_token: C) -> Self {
+		self.fee_token = Some(fee_token);
+		self
+	}
+
+	/// Total length in bytes of both encrypted outputs.
+	pub fn encrypted_outputs_len(&self) -> u32 {
+		self.encrypted_output1.len().saturating_add(self.encrypted_output2.len()) as u32
+	}
 }
 
-impl<I: Encode, A: Encode, B: Encode, C: Encode> IntoAbiToken for ExtData<I, A, B, C> {
+impl<I: Encode, A: Encode + IntoAbiToken, B: Encode, C: Encode> IntoAbiToken
+	for ExtData<I, A, B, C>
+{
 	// (bytes recipient,int256 extAmount,bytes relayer,uint256 fee,uint256
 	// refund,bytes token,bytes encryptedOutput1,bytes encryptedOutput2)
+	//
+	// Ext data naming a fee token has no EVM counterpart and is encoded as
+	// `((ext data),bytes feeToken)`, nesting the tuple above instead of extending
+	// it, so that its hash can never match the hash of ext data without one.
 	fn into_abi(&self) -> Token {
 		let recipient = Token::Bytes(self.recipient.encode());
-		let ext_amount = Token::Int(Int::from_little_endian(&self.ext_amount.encode()));
+		let ext_amount = self.ext_amount.into_abi();
 		let relayer = Token::Bytes(self.relayer.encode());
 		let fee = Token::Uint(Uint::from_little_endian(&self.fee.encode()));
 		let refund = Token::Uint(Uint::from_little_endian(&self.refund.encode()));
@@ -95,38 +175,92 @@ impl<I: Encode, A: Encode, B: Encode, C: Encode> IntoAbiToken for ExtData<I, A,
 			encrypted_output1,
 			encrypted_output2,
 		];
-		Token::Tuple(ext_data_args)
+		match &self.fee_token {
+			Some(fee_token) =>
+				Token::Tuple(vec![Token::Tuple(ext_data_args), Token::Bytes(fee_token.encode())]),
+			None => Token::Tuple(ext_data_args),
+		}
+	}
+}
+
+/// The current version of the [`EncryptedOutputEnvelope`] format.
+pub const ENCRYPTED_OUTPUT_ENVELOPE_VERSION: u8 = 1;
+
+/// Length in bytes of the ephemeral public key of an [`EncryptedOutputEnvelope`].
+pub const EPHEMERAL_KEY_LENGTH: usize = 32;
+
+/// Structured encrypted output, laid out as `version || ephemeral_key || ciphertext`.
+///
+/// The ephemeral key is the sender's public key for the key agreement with the
+/// recipient of the note, which lets wallets reject malformed outputs before
+/// attempting to decrypt them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EncryptedOutputEnvelope {
+	pub version: u8,
+	pub ephemeral_key: [u8; EPHEMERAL_KEY_LENGTH],
+	pub ciphertext: Vec<u8>,
+}
+
+impl EncryptedOutputEnvelope {
+	pub fn new(ephemeral_key: [u8; EPHEMERAL_KEY_LENGTH], ciphertext: Vec<u8>) -> Self {
+		Self { version: ENCRYPTED_OUTPUT_ENVELOPE_VERSION, ephemeral_key, ciphertext }
+	}
+
+	/// Parses an envelope, returning `None` if the version is unknown or the
+	/// ciphertext is missing.
+	pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+		let (version, rest) = bytes.split_first()?;
+		if *version != ENCRYPTED_OUTPUT_ENVELOPE_VERSION || rest.len() <= EPHEMERAL_KEY_LENGTH {
+			return None
+		}
+		let (ephemeral_key, ciphertext) = rest.split_at(EPHEMERAL_KEY_LENGTH);
+		Some(Self {
+			version: *version,
+			ephemeral_key: ephemeral_key.try_into().ok()?,
+			ciphertext: ciphertext.to_vec(),
+		})
+	}
+
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(1 + EPHEMERAL_KEY_LENGTH + self.ciphertext.len());
+		bytes.push(self.version);
+		bytes.extend_from_slice(&self.ephemeral_key);
+		bytes.extend_from_slice(&self.ciphertext);
+		bytes
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::hasher::InstanceHasher;
+	use crate::{
+		hasher::InstanceHasher,
+		types::{amount::I256, encode},
+	};
 	use core::convert::TryInto;
+	use sp_core::U256;
 
 	use super::*;
 
-	#[test]
-	fn ext_data_hash_works() {
-		type AccountId = [u8; 32];
-		type Amount = i128;
-		type Balance = u128;
-		type AssetId = u32;
+	type AccountId = [u8; 32];
+	type Balance = u128;
+	type AssetId = u32;
 
+	fn test_ext_data<A: Encode + IntoAbiToken>(
+		ext_amount: A,
+	) -> ExtData<AccountId, A, Balance, AssetId> {
 		let recipient: AccountId =
 			hex::decode("306721211d5404bd9da88e0204360a1a9ab8b87c66c1bc2fcdd37f3c2222cc20")
 				.unwrap()
 				.try_into()
 				.unwrap();
 		let relayer: AccountId = recipient;
-		let ext_amount: Amount = -1000000000000000000000;
 		let fee: Balance = 0;
 		let refund: Balance = 0;
 		let token: AssetId = 0;
 		let encrypted_output1 = hex::decode("4857e108572669341113cbe18b92defdd8ec1d2e54c7b39ea32007ce1df1232a743b9cef820f62b918825d207513b892e07908a89332c52a5fadc6bd2b5c2c8f3fad3768cb303e42bf43ecdb8779c72942401485f600c6a89cf48406fc12702e9b416bdd128b672e7d0f677aca180bb687ab2945208fdbf0d7f231d109a04d5a063c7728dd474d4709c9c6b78b20a5ad8d66ab3bf70ccce13f430fe09cca015d91d1124b3cb3a445").unwrap();
 		let encrypted_output2 = hex::decode("b57f36d4a39a9a571f65d0f7c1dbe80862925f73dc6fd5ccf8e2e196c4a7d37986497f5337b3bb29137128d4310e76525371602387999724d32fbddc898c6e8234e9756e48eee766e96196ad390f48ee5b8581407f65398f2c18ecf5d1edf92c8ed33ddff666d6cf6ca36e036a09124732d060c2029ab50e3bd223b33c69f0f28e979434b2b4abc72eb3eeb62dbfb4afdde749244adec2c0b00d6ce26361e02c8e32e83dba939472").unwrap();
 
-		let ext_data = ExtData::new(
+		ExtData::new(
 			recipient,
 			relayer,
 			ext_amount,
@@ -135,7 +269,13 @@ mod tests {
 			token,
 			encrypted_output1,
 			encrypted_output2,
-		);
+		)
+	}
+
+	#[test]
+	fn ext_data_hash_works() {
+		let ext_amount: i128 = -1000000000000000000000;
+		let ext_data = test_ext_data(ext_amount);
 		let ext_data_bytes = ext_data.encode_abi();
 		eprintln!("ext_data_bytes: 0x{}", hex::encode(&ext_data_bytes));
 		let hash1 = crate::hashing::ethereum::keccak_256(&ext_data_bytes);
@@ -145,7 +285,75 @@ mod tests {
 			Default::default(),
 		)
 		.unwrap();
-		let expected_hash = "04b18a1a64975a01e67f70e790434edd7d85bf51935580f3ddbe20e0c9abecc8";
+		// Negative amounts are sign-extended, matching the EVM and the `I256` encoding
+		let expected_hash = "1cf7b7e06d3f3eb3a459f9e3e242caa085c3eba8d15d418508002d191ff2e2b8";
 		assert_eq!(hex::encode(hash), expected_hash);
+		assert_eq!(ext_data_bytes, test_ext_data(I256::from(ext_amount)).encode_abi());
+	}
+
+	#[test]
+	fn ext_data_hash_with_fee_token_differs() {
+		let ext_data = test_ext_data(-1000000000000000000000);
+		let hash = crate::hashing::ethereum::keccak_256(&ext_data.encode_abi());
+		let with_fee_token = ext_data.clone().with_fee_token(1);
+		let hash_with_fee_token =
+			crate::hashing::ethereum::keccak_256(&with_fee_token.encode_abi());
+		assert_ne!(hash_with_fee_token, hash);
+		assert_ne!(
+			crate::hashing::ethereum::keccak_256(&ext_data.with_fee_token(2).encode_abi()),
+			hash_with_fee_token
+		);
+		// the fee token nests the plain ext data
+		assert_eq!(
+			with_fee_token.encode_abi(),
+			encode(&[Token::Tuple(vec![
+				test_ext_data(-1000000000000000000000).into_abi(),
+				Token::Bytes(1u32.encode())
+			])])
+		);
+	}
+
+	#[test]
+	fn ext_data_hash_matches_evm_for_256_bit_amounts() {
+		let hash_of = |ext_amount: I256| {
+			hex::encode(
+				crate::hashing::ethereum::Keccak256HasherBn254::hash(
+					&test_ext_data(ext_amount).encode_abi(),
+					Default::default(),
+				)
+				.unwrap(),
+			)
+		};
+		let above_i128 = I256(U256::one() << 130);
+		let minus_above_i128 = I256::default().checked_sub(&above_i128).unwrap();
+
+		assert_eq!(
+			hash_of(I256::from(-1000000000000000000000i128)),
+			"1cf7b7e06d3f3eb3a459f9e3e242caa085c3eba8d15d418508002d191ff2e2b8"
+		);
+		assert_eq!(
+			hash_of(above_i128),
+			"02ad75b16f67fa59947c1627ff2791fae804824b73325312b24ebbbb1c5e94c6"
+		);
+		assert_eq!(
+			hash_of(minus_above_i128),
+			"1062d3c6ce4c7eb1add72dc04b640b894784d90018dd40e83e92515b91490693"
+		);
+	}
+
+	#[test]
+	fn encrypted_output_envelope_works() {
+		let envelope = EncryptedOutputEnvelope::new([7u8; EPHEMERAL_KEY_LENGTH], vec![1, 2, 3]);
+		let bytes = envelope.to_bytes();
+		assert_eq!(bytes.len(), 1 + EPHEMERAL_KEY_LENGTH + 3);
+		assert_eq!(EncryptedOutputEnvelope::from_bytes(&bytes), Some(envelope));
+
+		// Missing ciphertext
+		assert_eq!(EncryptedOutputEnvelope::from_bytes(&bytes[..1 + EPHEMERAL_KEY_LENGTH]), None);
+		// Unknown version
+		let mut unknown_version = bytes;
+		unknown_version[0] = ENCRYPTED_OUTPUT_ENVELOPE_VERSION + 1;
+		assert_eq!(EncryptedOutputEnvelope::from_bytes(&unknown_version), None);
+		assert_eq!(EncryptedOutputEnvelope::from_bytes(&[]), None);
 	}
 }
@@ -51,14 +51,16 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::SyncCryptoStorePtr;
 use webb_primitives::{
-	opaque::Block, AccountId, Balance, BlockNumber, ChainId, Hash, Index, LeafIndex,
+	opaque::Block, AccountId, AssetId, Balance, BlockNumber, ChainId, Hash, Index, LeafIndex,
 };
 use webb_runtime::Element;
 
 pub use jsonrpc_core::IoHandler;
 
 use pallet_linkable_tree_rpc::LinkableTreeClient;
+use pallet_mixer_rpc::MixerClient;
 use pallet_mt_rpc::MerkleTreeClient;
+use pallet_signature_bridge_rpc::SignatureBridgeClient;
 
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
@@ -123,13 +125,23 @@ where
 	C::Api: pallet_mt_rpc_runtime_api::MerkleTreeApi<Block, Element>,
 	C::Api:
 		pallet_linkable_tree_rpc_runtime_api::LinkableTreeApi<Block, ChainId, Element, LeafIndex>,
+	C::Api: pallet_mixer_rpc_runtime_api::MixerApi<Block, Balance, AssetId, Element, LeafIndex>,
+	C::Api: pallet_signature_bridge_rpc_runtime_api::SignatureBridgeApi<
+		Block,
+		ChainId,
+		u32,
+		u32,
+		BlockNumber,
+	>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
 	use pallet_linkable_tree_rpc::LinkableTreeRpcApiServer;
+	use pallet_mixer_rpc::MixerRpcApiServer;
 	use pallet_mt_rpc::MerkleTreeRpcApiServer;
+	use pallet_signature_bridge_rpc::SignatureBridgeRpcApiServer;
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::BabeApiServer;
 	use sc_finality_grandpa_rpc::GrandpaApiServer;
@@ -193,6 +205,8 @@ where
 	io.merge(Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
 	io.merge(MerkleTreeClient::new(client.clone(), deny_unsafe).into_rpc())?;
-	io.merge(LinkableTreeClient::new(client, deny_unsafe).into_rpc())?;
+	io.merge(LinkableTreeClient::new(client.clone(), deny_unsafe).into_rpc())?;
+	io.merge(MixerClient::new(client.clone(), deny_unsafe).into_rpc())?;
+	io.merge(SignatureBridgeClient::new(client, deny_unsafe).into_rpc())?;
 	Ok(io)
 }
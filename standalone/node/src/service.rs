@@ -535,6 +535,10 @@ pub fn create_extrinsic(
 		frame_system::CheckNonce::<webb_runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<webb_runtime::Runtime>::new(),
 		pallet_asset_tx_payment::ChargeAssetTxPayment::<webb_runtime::Runtime>::from(tip, None),
+		pallet_vanchor::CheckVAnchorTransact::<
+			webb_runtime::Runtime,
+			pallet_vanchor::Instance1,
+		>::new(),
 	);
 
 	let raw_payload = webb_runtime::SignedPayload::from_raw(
@@ -549,6 +553,7 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -20,7 +20,7 @@
 use crate::{AccountId, Assets, Authorship, Balances, NegativeImbalance, Runtime};
 use frame_support::traits::{
 	fungibles::{Balanced, CreditOf},
-	Currency, OnUnbalanced,
+	Currency, Get, OnUnbalanced,
 };
 use pallet_asset_tx_payment::HandleCredit;
 
@@ -44,3 +44,12 @@ impl HandleCredit<AccountId, Assets> for CreditToBlockAuthor {
 		}
 	}
 }
+
+/// Resolves the author of the current block, who is paid the fee of unsigned
+/// VAnchor transactions.
+pub struct BlockAuthor;
+impl Get<Option<AccountId>> for BlockAuthor {
+	fn get() -> Option<AccountId> {
+		Authorship::author()
+	}
+}
@@ -19,11 +19,14 @@ pub mod impls;
 mod voter_bags;
 use frame_election_provider_support::{onchain, ExtendedBalance, SequentialPhragmen, VoteWeight};
 use frame_support::{
-	traits::{AsEnsureOriginWithArg, Contains, EitherOfDiverse, EqualPrivilegeOnly},
+	traits::{
+		AsEnsureOriginWithArg, Contains, ContainsPair, EitherOfDiverse, EqualPrivilegeOnly,
+		PalletInfoAccess,
+	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier},
 };
 use frame_system::EnsureSigned;
-use impls::{Author, CreditToBlockAuthor};
+use impls::{Author, BlockAuthor, CreditToBlockAuthor};
 use orml_traits::currency::MutationHooks;
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
@@ -79,7 +82,7 @@ use frame_support::{
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
-		ConstU128, ConstU16, ConstU32, Currency, Everything, Imbalance, InstanceFilter,
+		ConstBool, ConstU128, ConstU16, ConstU32, Currency, Everything, Imbalance, InstanceFilter,
 		KeyOwnerProofSystem, LockIdentifier, OnUnbalanced, U128CurrencyToVote,
 	},
 	weights::{
@@ -99,6 +102,10 @@ use webb_primitives::{
 	linkable_tree::LinkableTreeInspector,
 	merkle_tree::TreeInspector,
 	signing::SignatureVerifier,
+	types::{
+		mixer::MixerMetadata,
+		vanchor::{AssetSolvency, TreeFlows},
+	},
 	verifying::{ArkworksVerifierBn254, CircomVerifierBn254},
 	Amount, ChainId, LeafIndex,
 };
@@ -1039,6 +1046,7 @@ where
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+			pallet_vanchor::CheckVAnchorTransact::<Runtime, pallet_vanchor::Instance1>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1247,6 +1255,7 @@ impl orml_currencies::Config for Runtime {
 
 parameter_types! {
 	pub const MixerPalletId: PalletId = PalletId(*b"py/mixer");
+	pub const MixerMaxFee: Balance = Balance::MAX - 1;
 	pub const RegistryStringLimit: u32 = 10;
 }
 
@@ -1254,10 +1263,12 @@ impl pallet_mixer::Config<pallet_mixer::Instance1> for Runtime {
 	type Currency = Currencies;
 	type RuntimeEvent = RuntimeEvent;
 	type NativeCurrencyId = GetNativeCurrencyId;
+	type MaxFee = MixerMaxFee;
 	type PalletId = MixerPalletId;
 	type Tree = MerkleTreeBn254;
 	type Verifier = MixerVerifierBn254;
 	type ArbitraryHasher = Keccak256HasherBn254;
+	type TransactFilter = Denylist;
 	type WeightInfo = pallet_mixer::weights::WebbWeight<Runtime>;
 }
 
@@ -1284,6 +1295,13 @@ parameter_types! {
 	pub const MaxFee: Balance = Balance::MAX - 1;
 	pub const MaxExtAmount: Balance = Balance::MAX - 1;
 	pub const MaxCurrencyId: webb_primitives::AssetId = webb_primitives::AssetId::MAX - 1;
+	pub const VAnchorUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 4;
+	pub const VAnchorMaxUnsignedFeePriority: TransactionPriority = 1_000_000;
+	pub const VAnchorMinUnsignedFee: Balance = CENTS;
+	pub const MaxUnsignedTransactsPerBlock: u32 = 4;
+	pub const MaxEncryptedOutputLength: u32 = 1_024;
+	pub const WithdrawalWindowLength: BlockNumber = HOURS;
+	pub const WithdrawalReleaseDelay: BlockNumber = DAYS;
 }
 
 impl pallet_vanchor::Config<pallet_vanchor::Instance1> for Runtime {
@@ -1298,10 +1316,21 @@ impl pallet_vanchor::Config<pallet_vanchor::Instance1> for Runtime {
 	type MaxFee = MaxFee;
 	type MaxExtAmount = MaxExtAmount;
 	type MaxCurrencyId = MaxCurrencyId;
-	type PostDepositHook = ();
+	type PostDepositHook = VAnchorHandlerBn254;
 	type ProposalNonce = u32;
 	type NativeCurrencyId = GetNativeCurrencyId;
 	type TokenWrapper = TokenWrapper;
+	type BlockAuthor = BlockAuthor;
+	type UnsignedPriority = VAnchorUnsignedPriority;
+	type MaxUnsignedFeePriority = VAnchorMaxUnsignedFeePriority;
+	type MinUnsignedFee = VAnchorMinUnsignedFee;
+	type MaxUnsignedTransactsPerBlock = MaxUnsignedTransactsPerBlock;
+	type MaxEncryptedOutputLength = MaxEncryptedOutputLength;
+	type RequireEncryptedOutputEnvelope = ConstBool<false>;
+	type WithdrawalWindowLength = WithdrawalWindowLength;
+	type WithdrawalReleaseDelay = WithdrawalReleaseDelay;
+	type TransactFilter = Denylist;
+	type Mixer = MixerBn254;
 	type WeightInfo = ();
 }
 
@@ -1309,6 +1338,8 @@ impl pallet_vanchor_handler::Config<pallet_vanchor_handler::Instance1> for Runti
 	type VAnchor = VAnchorBn254;
 	type BridgeOrigin = pallet_signature_bridge::EnsureBridge<Runtime, SignatureBridgeInstance>;
 	type RuntimeEvent = RuntimeEvent;
+	type MaintainerSignature = SignatureBridge;
+	type MaxSignatureLength = MaxStringLength;
 }
 
 pub struct SetResourceProposalFilter;
@@ -1332,6 +1363,12 @@ impl Contains<RuntimeCall> for ExecuteProposalFilter {
 			RuntimeCall::VAnchorHandlerBn254(method) => match method {
 				pallet_vanchor_handler::Call::execute_vanchor_create_proposal { .. } => true,
 				pallet_vanchor_handler::Call::execute_vanchor_update_proposal { .. } => true,
+				pallet_vanchor_handler::Call::execute_set_max_deposit_limit_proposal { .. } => true,
+				pallet_vanchor_handler::Call::execute_set_min_withdrawal_limit_proposal {
+					..
+				} => true,
+				pallet_vanchor_handler::Call::execute_pause_proposal { .. } => true,
+				pallet_vanchor_handler::Call::execute_unpause_proposal { .. } => true,
 				_ => false,
 			},
 			RuntimeCall::TokenWrapperHandler(method) => match method {
@@ -1340,6 +1377,119 @@ impl Contains<RuntimeCall> for ExecuteProposalFilter {
 					..
 				} => true,
 				pallet_token_wrapper_handler::Call::execute_wrapping_fee_proposal { .. } => true,
+				pallet_token_wrapper_handler::Call::execute_set_fee_recipient_proposal {
+					..
+				} => true,
+				pallet_token_wrapper_handler::Call::execute_rescue_tokens_proposal { .. } => true,
+				_ => false,
+			},
+			_ => false,
+		}
+	}
+}
+
+pub struct TypedProposalDecoder;
+impl webb_primitives::signature_bridge::TypedProposalDecoder<RuntimeCall> for TypedProposalDecoder {
+	fn decode_proposal(
+		header: &webb_proposals::ProposalHeader,
+		proposal_data: &[u8],
+	) -> Option<RuntimeCall> {
+		use webb_primitives::proposals::TypedProposal;
+		let nonce = header.nonce().to_u32();
+		let call = match TypedProposal::decode(header, proposal_data)? {
+			TypedProposal::AnchorUpdate { merkle_root, src_resource_id } =>
+				RuntimeCall::VAnchorHandlerBn254(
+					pallet_vanchor_handler::Call::execute_vanchor_update_proposal {
+						r_id: header.resource_id(),
+						merkle_root: Element(merkle_root),
+						src_resource_id,
+						nonce,
+					},
+				),
+			TypedProposal::MaxDepositLimit(max_deposit_limit) => RuntimeCall::VAnchorHandlerBn254(
+				pallet_vanchor_handler::Call::execute_set_max_deposit_limit_proposal {
+					max_deposit_limit,
+					nonce,
+				},
+			),
+			TypedProposal::MinWithdrawalLimit(min_withdraw_limit) =>
+				RuntimeCall::VAnchorHandlerBn254(
+					pallet_vanchor_handler::Call::execute_set_min_withdrawal_limit_proposal {
+						min_withdraw_limit,
+						nonce,
+					},
+				),
+			TypedProposal::WrappingFeeUpdate { wrapping_fee_percent, into_pool_share_id } =>
+				RuntimeCall::TokenWrapperHandler(
+					pallet_token_wrapper_handler::Call::execute_wrapping_fee_proposal {
+						wrapping_fee_percent: wrapping_fee_percent.into(),
+						into_pool_share_id,
+						nonce,
+					},
+				),
+		};
+		Some(call)
+	}
+}
+
+pub struct SensitiveProposalFilter;
+impl Contains<RuntimeCall> for SensitiveProposalFilter {
+	fn contains(c: &RuntimeCall) -> bool {
+		match c {
+			RuntimeCall::VAnchorHandlerBn254(method) => matches!(
+				method,
+				pallet_vanchor_handler::Call::execute_set_max_deposit_limit_proposal { .. } |
+					pallet_vanchor_handler::Call::execute_set_min_withdrawal_limit_proposal { .. }
+			),
+			RuntimeCall::TokenWrapperHandler(method) => matches!(
+				method,
+				pallet_token_wrapper_handler::Call::execute_wrapping_fee_proposal { .. } |
+					pallet_token_wrapper_handler::Call::execute_set_fee_recipient_proposal { .. } |
+					pallet_token_wrapper_handler::Call::execute_rescue_tokens_proposal { .. }
+			),
+			_ => false,
+		}
+	}
+}
+
+parameter_types! {
+	/// Resource of the proposals that set the deposit and withdrawal limits of the vanchors.
+	///
+	/// It is addressed to the vanchor handler rather than to one of its anchors, and must be
+	/// registered on the bridge before these proposals can be executed.
+	pub VAnchorLimitsResourceId: webb_proposals::ResourceId =
+		webb_primitives::utils::derive_resource_id(
+			ChainIdentifier::get() as u32,
+			webb_proposals::SubstrateTargetSystem {
+				pallet_index: VAnchorHandlerBn254::index() as u8,
+				tree_id: u32::MAX,
+			},
+		);
+}
+
+pub struct ProposalResourceMatcher;
+impl ContainsPair<webb_proposals::ResourceId, RuntimeCall> for ProposalResourceMatcher {
+	fn contains(r_id: &webb_proposals::ResourceId, c: &RuntimeCall) -> bool {
+		use pallet_vanchor_handler::Call as VAnchorHandlerCall;
+		match c {
+			// VAnchor proposals must name the resource they were signed for, which in turn
+			// determines the tree they act upon
+			RuntimeCall::VAnchorHandlerBn254(method) => match method {
+				VAnchorHandlerCall::execute_vanchor_create_proposal { r_id: id, .. } |
+				VAnchorHandlerCall::execute_vanchor_update_proposal { r_id: id, .. } |
+				VAnchorHandlerCall::execute_set_resource_proposal { r_id: id } |
+				VAnchorHandlerCall::execute_pause_proposal { r_id: id, .. } |
+				VAnchorHandlerCall::execute_unpause_proposal { r_id: id, .. } => id == r_id,
+				// The limits are shared by every vanchor, so they are not bound to any anchor
+				VAnchorHandlerCall::execute_set_max_deposit_limit_proposal { .. } |
+				VAnchorHandlerCall::execute_set_min_withdrawal_limit_proposal { .. } =>
+					*r_id == VAnchorLimitsResourceId::get(),
+				_ => false,
+			},
+			// Token wrapper proposals must be addressed to the token wrapper handler
+			RuntimeCall::TokenWrapperHandler(_) => match r_id.target_system() {
+				webb_proposals::TargetSystem::Substrate(system) =>
+					system.pallet_index as usize == TokenWrapperHandler::index(),
 				_ => false,
 			},
 			_ => false,
@@ -1349,13 +1499,21 @@ impl Contains<RuntimeCall> for ExecuteProposalFilter {
 
 parameter_types! {
 	pub const ProposalLifetime: BlockNumber = 50;
+	pub const ExecutedProposalHistorySize: u32 = 10_000;
+	pub const ProposalDelay: BlockNumber = DAYS;
 	pub const BridgeAccountId: PalletId = PalletId(*b"dw/bridg");
 	pub const MaxStringLength: u32 = 1000;
+	pub const MaxMaintainers: u32 = 10;
+	pub const MaxProposalsPerBatch: u32 = 32;
 }
 
 type SignatureBridgeInstance = pallet_signature_bridge::Instance1;
 impl pallet_signature_bridge::Config<SignatureBridgeInstance> for Runtime {
-	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	// The bridge is administered by Root or by two thirds of the council
+	type AdminOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+	>;
 	type BridgeAccountId = BridgeAccountId;
 	type ChainId = ChainId;
 	type ChainIdentifier = ChainIdentifier;
@@ -1363,10 +1521,17 @@ impl pallet_signature_bridge::Config<SignatureBridgeInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Proposal = RuntimeCall;
 	type ProposalLifetime = ProposalLifetime;
+	type ExecutedProposalHistorySize = ExecutedProposalHistorySize;
+	type ProposalDelay = ProposalDelay;
 	type MaxStringLength = MaxStringLength;
+	type MaxMaintainers = MaxMaintainers;
+	type MaxProposalsPerBatch = MaxProposalsPerBatch;
 	type ProposalNonce = u32;
 	type SetResourceProposalFilter = SetResourceProposalFilter;
 	type ExecuteProposalFilter = ExecuteProposalFilter;
+	type ProposalResourceMatcher = ProposalResourceMatcher;
+	type SensitiveProposalFilter = SensitiveProposalFilter;
+	type ProposalDecoder = TypedProposalDecoder;
 	type MaintainerNonce = u32;
 	type SignatureVerifier = SignatureVerifier;
 	type WeightInfo = ();
@@ -1417,6 +1582,16 @@ impl pallet_key_storage::Config<pallet_key_storage::Instance1> for Runtime {
 	type WeightInfo = pallet_key_storage::weights::WebbWeight<Runtime>;
 }
 
+impl pallet_denylist::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Accounts can be denied by Root or by half of the council
+	type ManagerOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type WeightInfo = pallet_denylist::weights::WebbWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously
 // configured.
 construct_runtime!(
@@ -1489,7 +1664,7 @@ construct_runtime!(
 		MixerBn254: pallet_mixer::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// VAnchor
-		VAnchorBn254: pallet_vanchor::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
+		VAnchorBn254: pallet_vanchor::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>, ValidateUnsigned},
 
 		// VAnchor Handler
 		VAnchorHandlerBn254: pallet_vanchor_handler::<Instance1>::{Pallet, Call, Storage, Event<T>},
@@ -1502,6 +1677,9 @@ construct_runtime!(
 
 		// Key Storage
 		KeyStorage: pallet_key_storage::<Instance1>::{Pallet, Call, Storage, Event<T>},
+
+		// Denylist
+		Denylist: pallet_denylist::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -1530,6 +1708,8 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+	// Only `VAnchorBn254` (Instance1) is pre-validated, as it is the only vanchor instance
+	pallet_vanchor::CheckVAnchorTransact<Runtime, pallet_vanchor::Instance1>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -1545,7 +1725,14 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	OnRuntimeUpgrade,
+	(
+		OnRuntimeUpgrade,
+		pallet_vanchor::migrations::SeedFlows<Runtime, pallet_vanchor::Instance1>,
+		pallet_vanchor_handler::migrations::SeedAnchorResourceIds<
+			Runtime,
+			pallet_vanchor_handler::Instance1,
+		>,
+	),
 >;
 
 pub struct OnRuntimeUpgrade;
@@ -1769,6 +1956,78 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_mixer_rpc_runtime_api::MixerApi<Block, Balance, webb_primitives::AssetId, Element, LeafIndex> for Runtime {
+		fn get_mixers() -> Vec<(u32, MixerMetadata<Balance, webb_primitives::AssetId>)> {
+			MixerBn254::get_mixers()
+		}
+
+		fn is_nullifier_used(tree_id: u32, nullifier_hash: Element) -> bool {
+			MixerBn254::nullifier_hashes(tree_id, nullifier_hash)
+		}
+
+		fn get_leaf_count(tree_id: u32) -> Option<LeafIndex> {
+			MixerBn254::mixers(tree_id)?;
+			MerkleTreeBn254::trees(tree_id).map(|tree| tree.leaf_count)
+		}
+	}
+
+	impl pallet_vanchor_rpc_runtime_api::VAnchorApi<Block, Balance, webb_primitives::AssetId> for Runtime {
+		fn remaining_withdrawal_capacity(tree_id: u32) -> Option<Balance> {
+			VAnchorBn254::remaining_withdrawal_capacity(tree_id)
+		}
+
+		fn tree_flows(tree_id: u32) -> TreeFlows<Balance> {
+			VAnchorBn254::tree_flows(tree_id)
+		}
+
+		fn solvency() -> Vec<AssetSolvency<webb_primitives::AssetId, Balance>> {
+			VAnchorBn254::solvency()
+		}
+	}
+
+	impl pallet_signature_bridge_rpc_runtime_api::SignatureBridgeApi<Block, ChainId, u32, u32, BlockNumber> for Runtime {
+		fn get_maintainers() -> Vec<Vec<u8>> {
+			SignatureBridge::maintainer_keys()
+		}
+
+		fn get_threshold() -> u32 {
+			SignatureBridge::maintainer_threshold()
+		}
+
+		fn get_aggregate_key() -> Option<Vec<u8>> {
+			SignatureBridge::maintainer_aggregate_key()
+		}
+
+		fn get_maintainer_nonce() -> u32 {
+			pallet_signature_bridge::MaintainerNonce::<Runtime, SignatureBridgeInstance>::get()
+		}
+
+		fn get_proposal_nonce() -> u32 {
+			SignatureBridge::proposal_nonce()
+		}
+
+		fn get_whitelisted_chains() -> Vec<(ChainId, u32)> {
+			SignatureBridge::whitelisted_chains()
+		}
+
+		fn get_resources() -> Vec<([u8; 32], u32)> {
+			SignatureBridge::registered_resources()
+				.into_iter()
+				.map(|(r_id, nonce)| (r_id.0, nonce))
+				.collect()
+		}
+
+		fn get_proposal_execution_block(proposal_hash: [u8; 32]) -> Option<BlockNumber> {
+			SignatureBridge::proposal_executed_at(proposal_hash)
+		}
+	}
+
+	impl pallet_vanchor_handler_rpc_runtime_api::VAnchorHandlerApi<Block> for Runtime {
+		fn get_pending_anchor_updates() -> Vec<(u32, webb_proposals::ResourceId, u32, Vec<u8>)> {
+			VAnchorHandlerBn254::pending_anchor_update_proposals()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1790,6 +2049,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_signature_bridge, SignatureBridge);
 			list_benchmark!(list, extra, pallet_relayer_registry, RelayerRegistry);
 			list_benchmark!(list, extra, pallet_key_storage, KeyStorage);
+			list_benchmark!(list, extra, pallet_denylist, Denylist);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1829,9 +2089,84 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_signature_bridge, SignatureBridge);
 			add_benchmark!(params, batches, pallet_relayer_registry, RelayerRegistry);
 			add_benchmark!(params, batches, pallet_key_storage, KeyStorage);
+			add_benchmark!(params, batches, pallet_denylist, Denylist);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn anchor_resource_id(tree_id: u32) -> webb_proposals::ResourceId {
+		webb_primitives::utils::derive_resource_id(
+			ChainIdentifier::get() as u32,
+			webb_proposals::SubstrateTargetSystem {
+				pallet_index: VAnchorHandlerBn254::index() as u8,
+				tree_id,
+			},
+		)
+	}
+
+	#[test]
+	fn bridge_proposals_can_pause_and_unpause_vanchors() {
+		let r_id = anchor_resource_id(0);
+		let calls = [
+			RuntimeCall::VAnchorHandlerBn254(
+				pallet_vanchor_handler::Call::execute_pause_proposal {
+					r_id,
+					status: webb_primitives::types::vanchor::PauseStatus::All,
+					nonce: 1,
+				},
+			),
+			RuntimeCall::VAnchorHandlerBn254(
+				pallet_vanchor_handler::Call::execute_unpause_proposal { r_id, nonce: 2 },
+			),
+		];
+		for call in calls {
+			assert!(ExecuteProposalFilter::contains(&call));
+			assert!(ProposalResourceMatcher::contains(&r_id, &call));
+			// a proposal signed for another anchor cannot pause this one
+			assert!(!ProposalResourceMatcher::contains(&anchor_resource_id(1), &call));
+		}
+	}
+
+	#[test]
+	fn sensitive_token_wrapper_proposals_reach_the_queue() {
+		let r_id = webb_primitives::utils::derive_resource_id(
+			ChainIdentifier::get() as u32,
+			webb_proposals::SubstrateTargetSystem {
+				pallet_index: TokenWrapperHandler::index() as u8,
+				tree_id: 0,
+			},
+		);
+		let account = AccountId::new([1u8; 32]);
+		let calls = [
+			RuntimeCall::TokenWrapperHandler(
+				pallet_token_wrapper_handler::Call::execute_set_fee_recipient_proposal {
+					pool_share_id: 1,
+					fee_recipient: account.clone(),
+					nonce: 1,
+				},
+			),
+			RuntimeCall::TokenWrapperHandler(
+				pallet_token_wrapper_handler::Call::execute_rescue_tokens_proposal {
+					pool_share_id: 1,
+					asset_id: 0,
+					amount: 1,
+					recipient: account,
+					nonce: 2,
+				},
+			),
+		];
+		for call in calls {
+			// the bridge executes them, but only through the timelocked queue
+			assert!(ExecuteProposalFilter::contains(&call));
+			assert!(ProposalResourceMatcher::contains(&r_id, &call));
+			assert!(SensitiveProposalFilter::contains(&call));
+		}
+	}
+}